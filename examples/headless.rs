@@ -0,0 +1,62 @@
+//! Runs the IBM logo without a window, exercising the public emulator API.
+//!
+//! ```terminal
+//! cargo run --example headless
+//! ```
+
+use chip_8_emulator::chip8::{
+    cartridge::{Cartridge, Platform},
+    diagnostics::Policy,
+    emulator::Emulator,
+    keypad::Key,
+    quirks::Quirks,
+};
+
+fn main() -> chip_8_emulator::Result<()> {
+    let rom = std::fs::read("./roms/IBM Logo.ch8")?;
+    let cartridge = Cartridge::from_bytes("IBM Logo", &rom);
+    assert_eq!(cartridge.platform(), Platform::Chip8);
+
+    let mut emulator = Emulator::builder()
+        .quirks(Quirks {
+            shift_in_place: false,
+            ..Quirks::default()
+        })
+        .build();
+    emulator.set_illegal_instruction_policy(Policy::Trap);
+    emulator.load_cartridge(&cartridge)?;
+
+    emulator.key_pressed(Key::A);
+    emulator.key_released(Key::A);
+    emulator.set_keys(0);
+
+    emulator.tick_frames(60);
+
+    assert_eq!(emulator.fault(), None);
+    assert_eq!(emulator.diagnostics().illegal_instructions(), 0);
+    assert_eq!(emulator.diagnostics().reserved_fetches(), 0);
+
+    let framebuffer = (0..32)
+        .map(|y| {
+            (0..64)
+                .map(|x| if emulator.is_pixel_on(x, y) { '#' } else { ' ' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    // top left corner of the "I"
+    assert!(emulator.is_pixel_on(12, 8));
+    assert!(!emulator.is_pixel_on(0, 0));
+    assert_eq!(
+        framebuffer.iter().filter(|row| row.contains('#')).count(),
+        8
+    );
+
+    emulator.set_paused(true);
+    emulator.tick_frames(60);
+    assert_eq!(emulator.fault(), None);
+
+    println!("{}", framebuffer.join("\n"));
+
+    Ok(())
+}
@@ -1,16 +1,36 @@
 use bevy::prelude::*;
 
-use crate::{camera, chip8, display, emulator, window};
+use crate::{camera, chip8::quirks::Quirks, display, emulator, palette, sound, window};
 
 pub fn run() {
+    run_emulators(emulator::resource::Emulators::default());
+}
+
+/// Runs an emulator per set of quirks side by side, every dropped ROM is loaded into all of them
+pub fn compare(quirks: &[Quirks]) {
+    run_emulators(emulator::resource::Emulators::new(
+        quirks.iter().copied().map(Some).collect(),
+    ));
+}
+
+fn run_emulators(emulators: emulator::resource::Emulators) {
     let mut app = App::new();
 
-    app.insert_resource(window::resource())
-        .insert_resource(chip8::emulator::Emulator::new())
+    let scale = display::resource::Scale::default();
+
+    app.insert_resource(window::resource(&scale, emulators.len()))
+        .insert_resource(ClearColor(Color::BLACK))
+        .insert_resource(scale)
+        .insert_resource(display::resource::PixelColors::default())
+        .insert_resource(emulators)
+        .insert_resource(emulator::resource::KeyBindings::default())
+        .insert_resource(palette::Palette::default())
+        .insert_resource(sound::resource::Sound::default())
         .add_plugins(DefaultPlugins)
         .add_startup_system(camera::system::spawn)
         .add_plugin(display::plugin::Plugin)
         .add_plugin(emulator::plugin::Plugin)
+        .add_plugin(sound::plugin::Plugin)
         .add_system(bevy::input::system::exit_on_esc_system);
 
     #[cfg(feature = "editor")]
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+
+use super::operation::{
+    Operation, ADD1, ADD2, ADDI, AND2, CALL, CLS, DRW, EXIT, HIGH, JP, JPV0, LD1, LD2, LDB, LDDTV,
+    LDF, LDHF, LDI, LDIV, LDK, LDRV, LDST, LDVDT, LDVI, LDVR, LOW, OR, RET, RND, SCD, SCL, SCR,
+    SE1, SE2, SHL, SHR, SKNP, SKP, SNE1, SNE2, SUB, SUBN, SYS, UNKNOWN, XOR,
+};
+
+/// Where ROMs are loaded, the address of the first assembled instruction
+const START: usize = 0x200;
+
+/// Mnemonics [`assemble`] knows, so operands it can't make sense of are told apart from typos
+const MNEMONICS: [&str; 27] = [
+    "CLS", "RET", "EXIT", "LOW", "HIGH", "SCD", "SCR", "SCL", "SYS", "JP", "CALL", "SE", "SNE",
+    "LD", "ADD", "OR", "AND", "XOR", "SUB", "SHR", "SUBN", "SHL", "RND", "DRW", "SKP", "SKNP",
+    "UNKNOWN",
+];
+
+/// Assembles a ROM from one instruction per line, written as [`disassemble`](super::disassembler::disassemble) writes them.
+///
+/// A label such as `loop:` names the address of the instruction after it and can be used wherever
+/// an address is expected. Numbers are decimal or `0x` hexadecimal, `;` starts a comment.
+///
+/// # Errors
+///
+/// Will return `Err` naming the line of an unknown mnemonic, a bad operand or an undefined label.
+pub fn assemble(source: &str) -> crate::Result<Vec<u8>> {
+    let mut labels = HashMap::new();
+    let mut lines = Vec::new();
+
+    for (number, line) in (1..).zip(source.lines()) {
+        let mut line = line.split(';').next().unwrap_or_default().trim();
+
+        if let Some((label, rest)) = line.split_once(':') {
+            let label = label.trim();
+            if !is_label(label) {
+                return Err(format!("line {}: bad label {}", number, label).into());
+            }
+            let address = u16::try_from(START + 2 * lines.len())?;
+            if labels.insert(label, address).is_some() {
+                return Err(format!("line {}: label {} is already defined", number, label).into());
+            }
+            line = rest.trim();
+        }
+
+        if !line.is_empty() {
+            lines.push((number, line));
+        }
+    }
+
+    let mut rom = Vec::with_capacity(2 * lines.len());
+    for (number, line) in lines {
+        let operation =
+            parse_line(line, &labels).map_err(|error| format!("line {}: {}", number, error))?;
+        rom.extend(operation.to_bytes());
+    }
+
+    Ok(rom)
+}
+
+#[derive(Clone, Copy)]
+enum Operand {
+    V(u8),
+    Number(u16),
+    I,
+    /// `[I]`, memory at I
+    IndirectI,
+    DT,
+    ST,
+    K,
+    F,
+    HF,
+    B,
+    R,
+}
+
+impl Operand {
+    fn parse(text: &str, labels: &HashMap<&str, u16>) -> Result<Operand, String> {
+        let upper = text.to_ascii_uppercase();
+
+        Ok(match upper.as_str() {
+            "I" => Operand::I,
+            "[I]" => Operand::IndirectI,
+            "DT" => Operand::DT,
+            "ST" => Operand::ST,
+            "K" => Operand::K,
+            "F" => Operand::F,
+            "HF" => Operand::HF,
+            "B" => Operand::B,
+            "R" => Operand::R,
+            _ if upper.len() == 2 && upper.starts_with('V') => {
+                let x = u8::from_str_radix(&upper[1..], 16)
+                    .map_err(|_| format!("bad register {}", text))?;
+                Operand::V(x)
+            }
+            _ if upper.starts_with("0X") => {
+                let number = u16::from_str_radix(&text[2..], 16)
+                    .map_err(|_| format!("bad number {}", text))?;
+                Operand::Number(number)
+            }
+            _ if upper.starts_with(|c: char| c.is_ascii_digit()) => {
+                let number = text.parse().map_err(|_| format!("bad number {}", text))?;
+                Operand::Number(number)
+            }
+            _ => match labels.get(text) {
+                Some(address) => Operand::Number(*address),
+                None if upper.len() > 2
+                    && upper.starts_with('V')
+                    && upper[1..].chars().all(|c| c.is_ascii_hexdigit()) =>
+                {
+                    return Err(format!("register {} is past VF", text))
+                }
+                None => return Err(format!("undefined label {}", text)),
+            },
+        })
+    }
+}
+
+fn parse_line(line: &str, labels: &HashMap<&str, u16>) -> Result<Operation, String> {
+    use Operand::{Number, B, DT, F, HF, I, K, R, ST, V};
+
+    let (mnemonic, operands) = match line.split_once(char::is_whitespace) {
+        Some((mnemonic, operands)) => (mnemonic, operands.split(',').map(str::trim).collect()),
+        None => (line, Vec::new()),
+    };
+    let mnemonic = mnemonic.to_ascii_uppercase();
+    let operands = operands
+        .into_iter()
+        .map(|operand| Operand::parse(operand, labels))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(match (mnemonic.as_str(), operands.as_slice()) {
+        ("CLS", []) => Operation::CLS(CLS::new()),
+        ("RET", []) => Operation::RET(RET::new()),
+        ("EXIT", []) => Operation::EXIT(EXIT::new()),
+        ("LOW", []) => Operation::LOW(LOW::new()),
+        ("HIGH", []) => Operation::HIGH(HIGH::new()),
+        ("SCD", [Number(n)]) => Operation::SCD(SCD::new(nibble(*n)?)),
+        ("SCR", []) => Operation::SCR(SCR::new()),
+        ("SCL", []) => Operation::SCL(SCL::new()),
+        ("SYS", [Number(nnn)]) => Operation::SYS(SYS::new(address(*nnn)?)),
+        ("JP", [Number(nnn)]) => Operation::JP(JP::new(address(*nnn)?)),
+        ("JP", [V(0x0), Number(nnn)]) => Operation::JPV0(JPV0::new(address(*nnn)?)),
+        ("CALL", [Number(nnn)]) => Operation::CALL(CALL::new(address(*nnn)?)),
+        ("SE", [V(x), Number(nn)]) => Operation::SE1(SE1::new(*x, byte(*nn)?)),
+        ("SE", [V(x), V(y)]) => Operation::SE2(SE2::new(*x, *y)),
+        ("SNE", [V(x), Number(nn)]) => Operation::SNE1(SNE1::new(*x, byte(*nn)?)),
+        ("SNE", [V(x), V(y)]) => Operation::SNE2(SNE2::new(*x, *y)),
+        ("LD", [V(x), Number(nn)]) => Operation::LD1(LD1::new(*x, byte(*nn)?)),
+        ("LD", [V(x), V(y)]) => Operation::LD2(LD2::new(*x, *y)),
+        ("LD", [I, Number(nnn)]) => Operation::LDI(LDI::new(address(*nnn)?)),
+        ("LD", [V(x), DT]) => Operation::LDVDT(LDVDT::new(*x)),
+        ("LD", [V(x), K]) => Operation::LDK(LDK::new(*x)),
+        ("LD", [DT, V(x)]) => Operation::LDDTV(LDDTV::new(*x)),
+        ("LD", [ST, V(x)]) => Operation::LDST(LDST::new(*x)),
+        ("LD", [F, V(x)]) => Operation::LDF(LDF::new(*x)),
+        ("LD", [HF, V(x)]) => Operation::LDHF(LDHF::new(*x)),
+        ("LD", [B, V(x)]) => Operation::LDB(LDB::new(*x)),
+        ("LD", [Operand::IndirectI, V(x)]) => Operation::LDIV(LDIV::new(*x)),
+        ("LD", [V(x), Operand::IndirectI]) => Operation::LDVI(LDVI::new(*x)),
+        ("LD", [R, V(x)]) => Operation::LDRV(LDRV::new(*x)),
+        ("LD", [V(x), R]) => Operation::LDVR(LDVR::new(*x)),
+        ("ADD", [V(x), Number(nn)]) => Operation::ADD1(ADD1::new(*x, byte(*nn)?)),
+        ("ADD", [V(x), V(y)]) => Operation::ADD2(ADD2::new(*x, *y)),
+        ("ADD", [I, V(x)]) => Operation::ADDI(ADDI::new(*x)),
+        ("OR", [V(x), V(y)]) => Operation::OR(OR::new(*x, *y)),
+        ("AND", [V(x), V(y)]) => Operation::AND2(AND2::new(*x, *y)),
+        ("XOR", [V(x), V(y)]) => Operation::XOR(XOR::new(*x, *y)),
+        ("SUB", [V(x), V(y)]) => Operation::SUB(SUB::new(*x, *y)),
+        ("SHR", [V(x), V(y)]) => Operation::SHR(SHR::new(*x, *y)),
+        ("SUBN", [V(x), V(y)]) => Operation::SUBN(SUBN::new(*x, *y)),
+        ("SHL", [V(x), V(y)]) => Operation::SHL(SHL::new(*x, *y)),
+        ("RND", [V(x), Number(nn)]) => Operation::RND(RND::new(*x, byte(*nn)?)),
+        ("DRW", [V(x), V(y), Number(n)]) => Operation::DRW(DRW::new(*x, *y, nibble(*n)?)),
+        ("SKP", [V(x)]) => Operation::SKP(SKP::new(*x)),
+        ("SKNP", [V(x)]) => Operation::SKNP(SKNP::new(*x)),
+        // raw bytes, as the disassembler lists data
+        ("UNKNOWN", [Number(opcode)]) => Operation::UNKNOWN(UNKNOWN::new(*opcode)),
+        (mnemonic, _) if MNEMONICS.contains(&mnemonic) => {
+            return Err(format!("bad operands for {}", mnemonic))
+        }
+        (mnemonic, _) => return Err(format!("unknown mnemonic {}", mnemonic)),
+    })
+}
+
+fn is_label(label: &str) -> bool {
+    label.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn address(nnn: u16) -> Result<u16, String> {
+    if nnn <= 0xFFF {
+        Ok(nnn)
+    } else {
+        Err(format!("address 0x{:X} is past 0xFFF", nnn))
+    }
+}
+
+fn byte(nn: u16) -> Result<u8, String> {
+    u8::try_from(nn).map_err(|_| format!("0x{:X} does not fit in a byte", nn))
+}
+
+fn nibble(n: u16) -> Result<u8, String> {
+    match u8::try_from(n) {
+        Ok(n) if n <= 0xF => Ok(n),
+        _ => Err(format!("0x{:X} does not fit in a nibble", n)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::disassembler::disassemble, *};
+
+    #[test]
+    fn test_assemble() {
+        // Arrange
+        let source = "\
+            ; counts V0 up, drawing it each time
+                CLS
+                LD V0, 0x00
+            loop:
+                LD F, V0
+                DRW V1, V2, 5
+                ADD V0, 1
+                JP loop
+        ";
+
+        // Act
+        let rom = assemble(source).unwrap();
+
+        // Assert
+        assert_eq!(
+            rom,
+            [0x00, 0xE0, 0x60, 0x00, 0xF0, 0x29, 0xD1, 0x25, 0x70, 0x01, 0x12, 0x04]
+        );
+    }
+
+    #[test]
+    fn test_assemble_disassemble_round_trip() {
+        // Arrange
+        let rom = std::fs::read("./roms/IBM Logo.ch8").unwrap();
+        let source = rom
+            .chunks_exact(2)
+            .map(|bytes| disassemble([bytes[0], bytes[1]]))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // Act
+        let assembled = assemble(&source).unwrap();
+
+        // Assert
+        assert_eq!(assembled, rom[..rom.len() / 2 * 2]);
+    }
+
+    #[test]
+    fn test_assemble_every_mnemonic() {
+        for opcode in [
+            0x00C5, 0x00E0, 0x00EE, 0x00FB, 0x00FC, 0x00FD, 0x00FE, 0x00FF, 0x0123, 0x1228, 0x2ABC,
+            0x3123, 0x4234, 0x5340, 0x6456, 0x7567, 0x8670, 0x8781, 0x8892, 0x89A3, 0x8AB4, 0x8BC5,
+            0x8CD6, 0x8DE7, 0x8EFE, 0x9F00, 0xA123, 0xB234, 0xC3FF, 0xD45F, 0xE59E, 0xE6A1, 0xF707,
+            0xF80A, 0xF915, 0xFA18, 0xFB1E, 0xFC29, 0xFC30, 0xFD33, 0xFE55, 0xFF65, 0xF775, 0xF385,
+            0xFFFF,
+        ] {
+            // Arrange
+            let bytes = u16::to_be_bytes(opcode);
+
+            // Act
+            let assembled = assemble(&disassemble(bytes)).unwrap();
+
+            // Assert
+            assert_eq!(assembled, bytes, "{:04X}", opcode);
+        }
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        for (source, error) in [
+            ("CLS\nFOO V0", "line 2: unknown mnemonic FOO"),
+            ("LD V0, 0x100", "line 1: 0x100 does not fit in a byte"),
+            ("DRW V0, V1, 16", "line 1: 0x10 does not fit in a nibble"),
+            ("JP 0x1000", "line 1: address 0x1000 is past 0xFFF"),
+            ("JP nowhere", "line 1: undefined label nowhere"),
+            ("\n\nSKP 0x1", "line 3: bad operands for SKP"),
+            ("a:\na:", "line 2: label a is already defined"),
+            ("LD V0, 0x1FF", "line 1: 0x1FF does not fit in a byte"),
+            ("RND V0, 256", "line 1: 0x100 does not fit in a byte"),
+            ("LD V0, 0x10000", "line 1: bad number 0x10000"),
+            ("LD I, 0x1000", "line 1: address 0x1000 is past 0xFFF"),
+            ("JP V0, 4096", "line 1: address 0x1000 is past 0xFFF"),
+            ("SCD 0x10", "line 1: 0x10 does not fit in a nibble"),
+            ("ADD V10, 1", "line 1: register V10 is past VF"),
+            ("SE V0, V1F", "line 1: register V1F is past VF"),
+            ("LD VG, 1", "line 1: bad register VG"),
+        ] {
+            assert_eq!(assemble(source).unwrap_err().to_string(), error);
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use super::operation::*;
+
+/// Builds a ROM from operations, so tests can describe programs instead of raw opcodes
+pub(super) fn assemble(operations: &[Operation]) -> Vec<u8> {
+    operations.iter().flat_map(Operation::to_bytes).collect()
+}
+
+pub(super) fn scd(n: u8) -> [u8; 2] {
+    Operation::SCD(SCD::new(n)).to_bytes()
+}
+
+pub(super) fn cls() -> [u8; 2] {
+    Operation::CLS(CLS::new()).to_bytes()
+}
+
+pub(super) fn ret() -> [u8; 2] {
+    Operation::RET(RET::new()).to_bytes()
+}
+
+pub(super) fn scr() -> [u8; 2] {
+    Operation::SCR(SCR::new()).to_bytes()
+}
+
+pub(super) fn scl() -> [u8; 2] {
+    Operation::SCL(SCL::new()).to_bytes()
+}
+
+pub(super) fn exit() -> [u8; 2] {
+    Operation::EXIT(EXIT::new()).to_bytes()
+}
+
+pub(super) fn low() -> [u8; 2] {
+    Operation::LOW(LOW::new()).to_bytes()
+}
+
+pub(super) fn high() -> [u8; 2] {
+    Operation::HIGH(HIGH::new()).to_bytes()
+}
+
+pub(super) fn sys(nnn: u16) -> [u8; 2] {
+    Operation::SYS(SYS::new(nnn)).to_bytes()
+}
+
+pub(super) fn jp(nnn: u16) -> [u8; 2] {
+    Operation::JP(JP::new(nnn)).to_bytes()
+}
+
+pub(super) fn call(nnn: u16) -> [u8; 2] {
+    Operation::CALL(CALL::new(nnn)).to_bytes()
+}
+
+pub(super) fn se1(x: u8, nn: u8) -> [u8; 2] {
+    Operation::SE1(SE1::new(x, nn)).to_bytes()
+}
+
+pub(super) fn sne1(x: u8, nn: u8) -> [u8; 2] {
+    Operation::SNE1(SNE1::new(x, nn)).to_bytes()
+}
+
+pub(super) fn se2(x: u8, y: u8) -> [u8; 2] {
+    Operation::SE2(SE2::new(x, y)).to_bytes()
+}
+
+pub(super) fn ld1(x: u8, nn: u8) -> [u8; 2] {
+    Operation::LD1(LD1::new(x, nn)).to_bytes()
+}
+
+pub(super) fn add1(x: u8, nn: u8) -> [u8; 2] {
+    Operation::ADD1(ADD1::new(x, nn)).to_bytes()
+}
+
+pub(super) fn ld2(x: u8, y: u8) -> [u8; 2] {
+    Operation::LD2(LD2::new(x, y)).to_bytes()
+}
+
+pub(super) fn or(x: u8, y: u8) -> [u8; 2] {
+    Operation::OR(OR::new(x, y)).to_bytes()
+}
+
+pub(super) fn and2(x: u8, y: u8) -> [u8; 2] {
+    Operation::AND2(AND2::new(x, y)).to_bytes()
+}
+
+pub(super) fn xor(x: u8, y: u8) -> [u8; 2] {
+    Operation::XOR(XOR::new(x, y)).to_bytes()
+}
+
+pub(super) fn add2(x: u8, y: u8) -> [u8; 2] {
+    Operation::ADD2(ADD2::new(x, y)).to_bytes()
+}
+
+pub(super) fn sub(x: u8, y: u8) -> [u8; 2] {
+    Operation::SUB(SUB::new(x, y)).to_bytes()
+}
+
+pub(super) fn shr(x: u8, y: u8) -> [u8; 2] {
+    Operation::SHR(SHR::new(x, y)).to_bytes()
+}
+
+pub(super) fn subn(x: u8, y: u8) -> [u8; 2] {
+    Operation::SUBN(SUBN::new(x, y)).to_bytes()
+}
+
+pub(super) fn shl(x: u8, y: u8) -> [u8; 2] {
+    Operation::SHL(SHL::new(x, y)).to_bytes()
+}
+
+pub(super) fn sne2(x: u8, y: u8) -> [u8; 2] {
+    Operation::SNE2(SNE2::new(x, y)).to_bytes()
+}
+
+pub(super) fn ldi(nnn: u16) -> [u8; 2] {
+    Operation::LDI(LDI::new(nnn)).to_bytes()
+}
+
+pub(super) fn jpv0(nnn: u16) -> [u8; 2] {
+    Operation::JPV0(JPV0::new(nnn)).to_bytes()
+}
+
+pub(super) fn rnd(x: u8, nn: u8) -> [u8; 2] {
+    Operation::RND(RND::new(x, nn)).to_bytes()
+}
+
+pub(super) fn drw(x: u8, y: u8, n: u8) -> [u8; 2] {
+    Operation::DRW(DRW::new(x, y, n)).to_bytes()
+}
+
+pub(super) fn skp(x: u8) -> [u8; 2] {
+    Operation::SKP(SKP::new(x)).to_bytes()
+}
+
+pub(super) fn sknp(x: u8) -> [u8; 2] {
+    Operation::SKNP(SKNP::new(x)).to_bytes()
+}
+
+pub(super) fn ldvdt(x: u8) -> [u8; 2] {
+    Operation::LDVDT(LDVDT::new(x)).to_bytes()
+}
+
+pub(super) fn ldk(x: u8) -> [u8; 2] {
+    Operation::LDK(LDK::new(x)).to_bytes()
+}
+
+pub(super) fn lddtv(x: u8) -> [u8; 2] {
+    Operation::LDDTV(LDDTV::new(x)).to_bytes()
+}
+
+pub(super) fn ldst(x: u8) -> [u8; 2] {
+    Operation::LDST(LDST::new(x)).to_bytes()
+}
+
+pub(super) fn addi(x: u8) -> [u8; 2] {
+    Operation::ADDI(ADDI::new(x)).to_bytes()
+}
+
+pub(super) fn ldf(x: u8) -> [u8; 2] {
+    Operation::LDF(LDF::new(x)).to_bytes()
+}
+
+pub(super) fn ldhf(x: u8) -> [u8; 2] {
+    Operation::LDHF(LDHF::new(x)).to_bytes()
+}
+
+pub(super) fn ldb(x: u8) -> [u8; 2] {
+    Operation::LDB(LDB::new(x)).to_bytes()
+}
+
+pub(super) fn ldiv(x: u8) -> [u8; 2] {
+    Operation::LDIV(LDIV::new(x)).to_bytes()
+}
+
+pub(super) fn ldvi(x: u8) -> [u8; 2] {
+    Operation::LDVI(LDVI::new(x)).to_bytes()
+}
+
+pub(super) fn ldrv(x: u8) -> [u8; 2] {
+    Operation::LDRV(LDRV::new(x)).to_bytes()
+}
+
+pub(super) fn ldvr(x: u8) -> [u8; 2] {
+    Operation::LDVR(LDVR::new(x)).to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    fn ibm_logo() -> Vec<u8> {
+        let mut rom = Vec::new();
+        let mut file = std::fs::File::open("./roms/IBM Logo.ch8").unwrap();
+        file.read_to_end(&mut rom).unwrap();
+        rom
+    }
+
+    #[test]
+    fn test_helpers_ibm_logo() {
+        // Arrange
+        let rom = ibm_logo();
+
+        // Act
+        let bytes = [
+            cls(),
+            ldi(0x22A),
+            ld1(0x0, 0x0C),
+            ld1(0x1, 0x08),
+            drw(0x0, 0x1, 0xF),
+            add1(0x0, 0x09),
+            ldi(0x239),
+            drw(0x0, 0x1, 0xF),
+        ]
+        .concat();
+
+        // Assert
+        assert_eq!(bytes, rom[..16]);
+    }
+
+    #[test]
+    fn test_assemble_ibm_logo() {
+        // Arrange
+        let rom = ibm_logo();
+
+        // Act
+        let bytes = assemble(&[
+            Operation::CLS(CLS::new()),
+            Operation::LDI(LDI::new(0x22A)),
+            Operation::LD1(LD1::new(0x0, 0x0C)),
+            Operation::LD1(LD1::new(0x1, 0x08)),
+            Operation::DRW(DRW::new(0x0, 0x1, 0xF)),
+            Operation::ADD1(ADD1::new(0x0, 0x09)),
+            Operation::LDI(LDI::new(0x239)),
+            Operation::DRW(DRW::new(0x0, 0x1, 0xF)),
+        ]);
+
+        // Assert
+        assert_eq!(bytes, rom[..16]);
+    }
+
+    #[test]
+    fn test_to_bytes_round_trip() {
+        for bytes in [
+            scd(0x5),
+            cls(),
+            ret(),
+            scr(),
+            scl(),
+            exit(),
+            low(),
+            high(),
+            sys(0x123),
+            jp(0x228),
+            call(0xABC),
+            se1(0x1, 0x23),
+            sne1(0x2, 0x34),
+            se2(0x3, 0x4),
+            ld1(0x4, 0x56),
+            add1(0x5, 0x67),
+            ld2(0x6, 0x7),
+            or(0x7, 0x8),
+            and2(0x8, 0x9),
+            xor(0x9, 0xA),
+            add2(0xA, 0xB),
+            sub(0xB, 0xC),
+            shr(0xC, 0xD),
+            subn(0xD, 0xE),
+            shl(0xE, 0xF),
+            sne2(0xF, 0x0),
+            ldi(0xFED),
+            jpv0(0xDCB),
+            rnd(0x1, 0xBA),
+            drw(0x2, 0x3, 0x4),
+            skp(0x5),
+            sknp(0x6),
+            ldvdt(0x7),
+            ldk(0x8),
+            lddtv(0x9),
+            ldst(0xA),
+            addi(0xB),
+            ldf(0xC),
+            ldhf(0xC),
+            ldrv(0x7),
+            ldvr(0x3),
+            ldb(0xD),
+            ldiv(0xE),
+            ldvi(0xF),
+        ] {
+            assert_eq!(Operation::parse(bytes).to_bytes(), bytes, "{:02X?}", bytes);
+        }
+    }
+}
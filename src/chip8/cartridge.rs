@@ -0,0 +1,167 @@
+use super::{operation::nibble, quirks::Quirks};
+
+/// Interpreter a ROM was written for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    /// Original COSMAC VIP CHIP-8
+    Chip8,
+    /// SUPER-CHIP 1.1
+    SuperChip,
+}
+
+impl Platform {
+    /// Follows the control flow from the entry point, so sprite data is never mistaken for code
+    fn detect(rom: &[u8]) -> Platform {
+        let mut visited = vec![false; rom.len()];
+        let mut pending = vec![0x0];
+
+        while let Some(offset) = pending.pop() {
+            if offset + 1 >= rom.len() || visited[offset] {
+                continue;
+            }
+            visited[offset] = true;
+
+            let bytes = [rom[offset], rom[offset + 1]];
+            let [_, n2, n3, n4] = nibble::from_bytes(bytes);
+            let nnn = usize::from(nibble::to_nnn(n2, n3, n4));
+
+            match bytes {
+                [0x00, 0xC0..=0xCF | 0xFB | 0xFC | 0xFD | 0xFE | 0xFF]
+                | [0xF0..=0xFF, 0x30 | 0x75 | 0x85] => return Platform::SuperChip,
+                // RET and JP V0, addr, neither has a destination known ahead of time
+                [0x00, 0xEE] | [0xB0..=0xBF, _] => (),
+                // JP addr
+                [0x10..=0x1F, _] => pending.extend(nnn.checked_sub(0x200)),
+                // CALL addr
+                [0x20..=0x2F, _] => {
+                    pending.extend(nnn.checked_sub(0x200));
+                    pending.push(offset + 2);
+                }
+                // SE, SNE, SKP, SKNP
+                [0x30..=0x5F | 0x90..=0x9F, _] | [0xE0..=0xEF, 0x9E | 0xA1] => {
+                    pending.push(offset + 2);
+                    pending.push(offset + 4);
+                }
+                _ => pending.push(offset + 2),
+            }
+        }
+
+        Platform::Chip8
+    }
+
+    fn quirks(self) -> Quirks {
+        match self {
+            Platform::Chip8 => Quirks::cosmac(),
+            Platform::SuperChip => Quirks::superchip(),
+        }
+    }
+}
+
+/// ROM bytes together with the metadata needed to run them
+pub struct Cartridge {
+    checksum: u32,
+    name: String,
+    platform: Platform,
+    quirks: Quirks,
+    rom: Vec<u8>,
+}
+
+impl Cartridge {
+    #[must_use]
+    pub fn from_bytes(name: &str, rom: &[u8]) -> Cartridge {
+        let platform = Platform::detect(rom);
+
+        Cartridge {
+            checksum: crc32(rom),
+            name: name.into(),
+            platform,
+            quirks: platform.quirks(),
+            rom: rom.into(),
+        }
+    }
+
+    /// CRC-32 of the ROM bytes
+    #[must_use]
+    pub fn checksum(&self) -> u32 {
+        self.checksum
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn platform(&self) -> Platform {
+        self.platform
+    }
+
+    #[must_use]
+    pub fn quirks(&self) -> Quirks {
+        self.quirks
+    }
+
+    #[must_use]
+    pub fn rom(&self) -> &[u8] {
+        &self.rom
+    }
+}
+
+/// CRC-32 (IEEE 802.3), the checksum used by most ROM databases
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            let mask = (crc & 0x1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    #[test]
+    fn test_from_bytes_ibm_logo() {
+        // Arrange
+        let mut rom = Vec::new();
+        let mut file = std::fs::File::open("./roms/IBM Logo.ch8").unwrap();
+        file.read_to_end(&mut rom).unwrap();
+
+        // Act
+        let cartridge = Cartridge::from_bytes("IBM Logo", &rom);
+
+        // Assert
+        assert_eq!(cartridge.name(), "IBM Logo");
+        assert_eq!(cartridge.platform(), Platform::Chip8);
+        assert_eq!(cartridge.quirks(), Platform::Chip8.quirks());
+        assert_eq!(cartridge.checksum(), crc32(&rom));
+        assert_eq!(cartridge.rom(), rom);
+    }
+
+    #[test]
+    fn test_from_bytes_super_chip() {
+        // Arrange
+        let rom = [0x00, 0xE0, 0x00, 0xFF, 0x12, 0x04];
+
+        // Act
+        let cartridge = Cartridge::from_bytes("high resolution", &rom);
+
+        // Assert
+        assert_eq!(cartridge.platform(), Platform::SuperChip);
+        assert!(cartridge.quirks().shift_in_place);
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0x0000_0000);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+}
@@ -1,34 +1,274 @@
 use super::{
-    display::Display, keypad::Keypad, memory::Memory, operation::Operation, register::Register,
+    diagnostics::{Diagnostics, Policy},
+    display::Display,
+    fault::Fault,
+    keypad::Keypad,
+    memory::Memory,
+    operation::Operation,
+    profile::ProfileStats,
+    quirks::Quirks,
+    register::Register,
     timer::Timer,
 };
 
-pub(super) struct Cpu;
+/// Bytes either side of an instruction which it modifies itself by writing to
+const SELF_MODIFYING_WINDOW: u16 = 0x10;
+
+/// How execution continues after an instruction
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum Flow {
+    Continue,
+    /// The instruction jumped to itself, nothing but a key press or timer can change from here
+    Halt,
+    /// The program asked the interpreter to exit
+    Exit,
+}
+
+pub(super) struct Cpu {
+    diagnostics: Diagnostics,
+    /// DRW instructions executed since the counter was last reset
+    draws: u32,
+    illegal_instruction: Policy,
+    /// Address and opcode of the most recently executed instruction
+    last_executed: Option<(u16, [u8; 2])>,
+    /// Levels of the stack, a CALL with all of them in use faults instead of growing the stack
+    max_stack_depth: usize,
+    /// Key an `Fx0A` saw pressed, the instruction completes once it is released
+    pending_key: Option<u8>,
+    profile: ProfileStats,
+    profiling: bool,
+    self_recursive_call: Policy,
+    /// Addresses and opcodes of the most recently executed instructions, oldest first
+    trace: std::collections::VecDeque<(u16, [u8; 2])>,
+    trace_depth: usize,
+}
 
 impl Cpu {
     pub(super) fn new() -> Cpu {
-        Cpu
+        Cpu {
+            diagnostics: Diagnostics::default(),
+            draws: 0,
+            illegal_instruction: Policy::Warn,
+            last_executed: None,
+            max_stack_depth: 16,
+            pending_key: None,
+            profile: ProfileStats::default(),
+            profiling: false,
+            self_recursive_call: Policy::Trap,
+            trace: std::collections::VecDeque::new(),
+            trace_depth: 0,
+        }
+    }
+
+    pub(super) fn diagnostics(&self) -> &Diagnostics {
+        &self.diagnostics
+    }
+
+    pub(super) fn draws(&self) -> u32 {
+        self.draws
+    }
+
+    pub(super) fn reset_draws(&mut self) {
+        self.draws = 0;
+    }
+
+    pub(super) fn illegal_instruction_policy(&self) -> Policy {
+        self.illegal_instruction
+    }
+
+    pub(super) fn last_executed(&self) -> Option<(u16, Operation)> {
+        self.last_executed
+            .map(|(address, opcode)| (address, Cpu::decode(opcode)))
+    }
+
+    /// Forgets the key an `Fx0A` is waiting to be released
+    pub(super) fn reset_pending_key(&mut self) {
+        self.pending_key = None;
+    }
+
+    pub(super) fn pending_key(&self) -> Option<u8> {
+        self.pending_key
+    }
+
+    pub(super) fn set_pending_key(&mut self, pending_key: Option<u8>) {
+        self.pending_key = pending_key;
+    }
+
+    pub(super) fn profile(&self) -> ProfileStats {
+        self.profile
+    }
+
+    pub(super) fn self_recursive_call_policy(&self) -> Policy {
+        self.self_recursive_call
+    }
+
+    pub(super) fn set_illegal_instruction_policy(&mut self, policy: Policy) {
+        self.illegal_instruction = policy;
+    }
+
+    pub(super) fn set_self_recursive_call_policy(&mut self, policy: Policy) {
+        self.self_recursive_call = policy;
+    }
+
+    pub(super) fn set_max_stack_depth(&mut self, depth: usize) {
+        self.max_stack_depth = depth;
+    }
+
+    pub(super) fn set_trace_depth(&mut self, depth: usize) {
+        self.trace_depth = depth;
+        while self.trace.len() > depth {
+            self.trace.pop_front();
+        }
+    }
+
+    /// Most recently executed instructions, oldest first
+    pub(super) fn trace(&self) -> impl Iterator<Item = (u16, Operation)> + '_ {
+        self.trace
+            .iter()
+            .map(|&(address, opcode)| (address, Cpu::decode(opcode)))
+    }
+
+    pub(super) fn set_profiling(&mut self, profiling: bool) {
+        self.profiling = profiling;
+    }
+
+    /// Profiling stays off on wasm32, where `Instant::now` panics in the browser
+    fn profiling(&self) -> bool {
+        self.profiling && cfg!(not(target_arch = "wasm32"))
+    }
+
+    /// Opcode at the program counter
+    pub(super) fn fetch(memory: &Memory, register: &Register) -> [u8; 2] {
+        [
+            memory.get_byte(register.get_program_counter()),
+            memory.get_byte(register.get_program_counter().wrapping_add(0x1)),
+        ]
     }
 
-    #[allow(clippy::unused_self)]
+    pub(super) fn decode(opcode: [u8; 2]) -> Operation {
+        Operation::parse(opcode)
+    }
+
+    /// Fetches, decodes and dispatches the instruction at the program counter
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn execute(
-        &self,
+        &mut self,
         register: &mut Register,
         display: &mut Display,
         keypad: &Keypad,
         memory: &mut Memory,
         delay_timer: &mut Timer,
         sound_timer: &mut Timer,
-    ) {
-        let instruction = Operation::parse([
-            memory.get_byte(register.get_program_counter()),
-            memory.get_byte(register.get_program_counter() + 0x1),
-        ]);
+        quirks: Quirks,
+    ) -> Result<Flow, Fault> {
+        let address = register.get_program_counter();
+        self.diagnostics.fetch(address);
+
+        let started = self.profiling().then(std::time::Instant::now);
+        let opcode = Cpu::fetch(memory, register);
+        let instruction = Cpu::decode(opcode);
+        if let Some(started) = started {
+            self.profile.parse += started.elapsed();
+        }
+
+        if (instruction.requires_high_res() && !display.is_high_res())
+            || (instruction.is_superchip() && !quirks.superchip_instructions)
+        {
+            match self.illegal_instruction {
+                Policy::Ignore => (),
+                Policy::Warn => self.diagnostics.illegal_instruction(),
+                Policy::Trap => return Err(Fault::IllegalInstruction { address }),
+            }
+        }
+
+        if matches!(&instruction, Operation::CALL(o) if o.nnn() == address) {
+            match self.self_recursive_call {
+                Policy::Ignore => (),
+                Policy::Warn => self.diagnostics.self_recursive_call(),
+                Policy::Trap => return Err(Fault::SelfRecursiveCall { address }),
+            }
+        }
+
+        if matches!(instruction, Operation::CALL(_))
+            && register.stack_depth() >= self.max_stack_depth
+        {
+            return Err(Fault::StackOverflow { address });
+        }
 
+        if matches!(instruction, Operation::SYS(_)) {
+            return Err(Fault::MachineCodeRoutine { address });
+        }
+
+        if matches!(instruction, Operation::UNKNOWN(_)) {
+            return Err(Fault::UnknownInstruction { address });
+        }
+
+        if matches!(instruction, Operation::DRW(_)) {
+            self.draws += 1;
+        }
+
+        let started = self.profiling().then(std::time::Instant::now);
+        let flow = Cpu::dispatch(
+            instruction,
+            register,
+            display,
+            keypad,
+            memory,
+            delay_timer,
+            sound_timer,
+            quirks,
+            &mut self.pending_key,
+        );
+        if let Some(started) = started {
+            self.profile.execute += started.elapsed();
+            self.profile.instructions += 1;
+        }
+
+        if let Some((low, high)) = memory.take_writes() {
+            if low <= address.saturating_add(SELF_MODIFYING_WINDOW)
+                && high >= address.saturating_sub(SELF_MODIFYING_WINDOW)
+            {
+                self.diagnostics.self_modifying_write();
+            }
+        }
+
+        self.last_executed = Some((address, opcode));
+        if self.trace_depth > 0 {
+            if self.trace.len() == self.trace_depth {
+                self.trace.pop_front();
+            }
+            self.trace.push_back((address, opcode));
+        }
+
+        Ok(flow)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn dispatch(
+        instruction: Operation,
+        register: &mut Register,
+        display: &mut Display,
+        keypad: &Keypad,
+        memory: &mut Memory,
+        delay_timer: &mut Timer,
+        sound_timer: &mut Timer,
+        quirks: Quirks,
+        pending_key: &mut Option<u8>,
+    ) -> Flow {
         match instruction {
+            Operation::JP(o) if o.nnn() == register.get_program_counter() => {
+                return Flow::Halt;
+            }
+            Operation::EXIT(_) => return Flow::Exit,
+            Operation::LOW(o) => o.execute(register, display),
+            Operation::HIGH(o) => o.execute(register, display),
+            Operation::SCD(o) => o.execute(register, display),
             Operation::CLS(o) => o.execute(register, display),
             Operation::RET(o) => o.execute(register),
-            Operation::SYS(o) => o.execute(),
+            Operation::SCR(o) => o.execute(register, display),
+            Operation::SCL(o) => o.execute(register, display),
+            // `execute` faults before dispatching a machine code routine
+            Operation::SYS(_) => unreachable!(),
             Operation::JP(o) => o.execute(register),
             Operation::CALL(o) => o.execute(register),
             Operation::SE1(o) => o.execute(register),
@@ -37,30 +277,519 @@ impl Cpu {
             Operation::LD1(o) => o.execute(register),
             Operation::ADD1(o) => o.execute(register),
             Operation::LD2(o) => o.execute(register),
-            Operation::OR(o) => o.execute(register),
-            Operation::AND2(o) => o.execute(register),
-            Operation::XOR(o) => o.execute(register),
+            Operation::OR(o) => o.execute(register, quirks),
+            Operation::AND2(o) => o.execute(register, quirks),
+            Operation::XOR(o) => o.execute(register, quirks),
             Operation::ADD2(o) => o.execute(register),
             Operation::SUB(o) => o.execute(register),
-            Operation::SHR(o) => o.execute(register),
+            Operation::SHR(o) => o.execute(register, quirks),
             Operation::SUBN(o) => o.execute(register),
-            Operation::SHL(o) => o.execute(register),
+            Operation::SHL(o) => o.execute(register, quirks),
             Operation::SNE2(o) => o.execute(register),
             Operation::LDI(o) => o.execute(register),
-            Operation::JPV0(o) => o.execute(register),
+            Operation::JPV0(o) => o.execute(register, quirks),
             Operation::RND(o) => o.execute(register),
-            Operation::DRW(o) => o.execute(register, display, memory),
+            Operation::DRW(o) => o.execute(register, display, memory, quirks),
             Operation::SKP(o) => o.execute(register, keypad),
             Operation::SKNP(o) => o.execute(register, keypad),
             Operation::LDVDT(o) => o.execute(register, delay_timer),
-            Operation::LDK(o) => o.execute(register, keypad),
+            Operation::LDK(o) => o.execute(register, keypad, pending_key),
             Operation::LDDTV(o) => o.execute(register, delay_timer),
             Operation::LDST(o) => o.execute(register, sound_timer),
-            Operation::ADDI(o) => o.execute(register),
+            Operation::ADDI(o) => o.execute(register, quirks),
             Operation::LDF(o) => o.execute(register),
+            Operation::LDHF(o) => o.execute(register),
             Operation::LDB(o) => o.execute(register, memory),
-            Operation::LDIV(o) => o.execute(register, memory),
-            Operation::LDVI(o) => o.execute(register, memory),
+            Operation::LDIV(o) => o.execute(register, memory, quirks),
+            Operation::LDVI(o) => o.execute(register, memory, quirks),
+            Operation::LDRV(o) => o.execute(register),
+            Operation::LDVR(o) => o.execute(register),
+            // `execute` faults before dispatching an unknown instruction
+            Operation::UNKNOWN(_) => unreachable!(),
+        }
+
+        Flow::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{assembler, font::Font},
+        *,
+    };
+
+    fn execute(cpu: &mut Cpu, register: &mut Register, memory: &mut Memory) -> Result<Flow, Fault> {
+        cpu.execute(
+            register,
+            &mut Display::new(),
+            &Keypad::new(),
+            memory,
+            &mut Timer::new(),
+            &mut Timer::new(),
+            Quirks::default(),
+        )
+    }
+
+    #[test]
+    fn test_fetch() {
+        // Arrange
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory
+            .load_rom(&[assembler::cls(), assembler::ld1(0x3, 0x42)].concat())
+            .unwrap();
+
+        // Act & Assert
+        assert_eq!(Cpu::fetch(&memory, &register), [0x00, 0xE0]);
+
+        register.set_program_counter(0x202);
+        assert_eq!(Cpu::fetch(&memory, &register), [0x63, 0x42]);
+
+        register.set_program_counter(0x201);
+        assert_eq!(Cpu::fetch(&memory, &register), [0xE0, 0x63]);
+    }
+
+    #[test]
+    fn test_decode() {
+        use super::super::operation::{CLS, DRW, LD1, LDHF, UNKNOWN};
+
+        assert_eq!(Cpu::decode([0x00, 0xE0]), Operation::CLS(CLS::new()));
+        assert_eq!(
+            Cpu::decode([0x63, 0x42]),
+            Operation::LD1(LD1::new(0x3, 0x42))
+        );
+        assert_eq!(
+            Cpu::decode([0xD0, 0x1F]),
+            Operation::DRW(DRW::new(0x0, 0x1, 0xF))
+        );
+        assert_eq!(Cpu::decode([0xF7, 0x30]), Operation::LDHF(LDHF::new(0x7)));
+        assert_eq!(
+            Cpu::decode([0xFF, 0xFF]),
+            Operation::UNKNOWN(UNKNOWN::new(0xFFFF))
+        );
+    }
+
+    #[test]
+    fn test_fetch_does_not_execute() {
+        // Arrange
+        let register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_rom(&assembler::ld1(0x3, 0x42)).unwrap();
+
+        // Act
+        let operation = Cpu::decode(Cpu::fetch(&memory, &register));
+
+        // Assert
+        assert_eq!(operation.to_string(), "LD V3, 0x42");
+        assert_eq!(register.get_program_counter(), 0x200);
+        assert_eq!(register.get_v(0x3), 0x00);
+    }
+
+    #[test]
+    fn test_shift_flag() {
+        let cases = [
+            // opcode, vx, result, vf
+            (assembler::shr(0x4, 0x4), 0b1000_0001, 0b0100_0000, 0x1),
+            (assembler::shr(0x4, 0x4), 0b1000_0000, 0b0100_0000, 0x0),
+            (assembler::shl(0x4, 0x4), 0b1000_0001, 0b0000_0010, 0x1),
+            (assembler::shl(0x4, 0x4), 0b0000_0001, 0b0000_0010, 0x0),
+        ];
+
+        for (opcode, vx, result, vf) in cases {
+            // Arrange
+            let mut cpu = Cpu::new();
+            let mut register = Register::new();
+            let mut memory = Memory::new();
+            memory.load_rom(&opcode).unwrap();
+            register.set_v(0x4, vx);
+
+            // Act
+            execute(&mut cpu, &mut register, &mut memory).unwrap();
+
+            // Assert
+            assert_eq!(register.get_v(0x4), result, "{:08b}", vx);
+            assert_eq!(register.get_v(0xF), vf, "{:08b}", vx);
+        }
+    }
+
+    #[test]
+    fn test_store_load_round_trip() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        let program = [
+            assembler::ld1(0x0, 0x12),
+            assembler::ld1(0x1, 0x34),
+            assembler::ld1(0x2, 0x56),
+            assembler::ld1(0x3, 0x78),
+            assembler::ldi(0x300),
+            assembler::ldiv(0x3),
+            assembler::ld1(0x0, 0x00),
+            assembler::ld1(0x1, 0x00),
+            assembler::ld1(0x2, 0x00),
+            assembler::ld1(0x3, 0x00),
+            assembler::ldi(0x301),
+            assembler::ldi(0x300),
+            assembler::ldvi(0x3),
+        ];
+        memory.load_rom(&program.concat()).unwrap();
+
+        // Act
+        for _ in 0..program.len() {
+            execute(&mut cpu, &mut register, &mut memory).unwrap();
+        }
+
+        // Assert
+        assert_eq!(memory.get_slice(0x300, 4), [0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(
+            (0x0..=0x3).map(|x| register.get_v(x)).collect::<Vec<_>>(),
+            vec![0x12, 0x34, 0x56, 0x78]
+        );
+        assert_eq!(register.get_v(0x4), 0x00);
+    }
+
+    #[test]
+    fn test_last_executed() {
+        use super::super::operation::{JP, LD1};
+
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory
+            .load_rom(
+                &[
+                    assembler::jp(0x204),
+                    assembler::cls(),
+                    assembler::ld1(0x3, 0x42),
+                ]
+                .concat(),
+            )
+            .unwrap();
+
+        // Act
+        execute(&mut cpu, &mut register, &mut memory).unwrap();
+
+        // Assert
+        assert_eq!(
+            cpu.last_executed(),
+            Some((0x200, Operation::JP(JP::new(0x204))))
+        );
+
+        // Act
+        execute(&mut cpu, &mut register, &mut memory).unwrap();
+
+        // Assert
+        assert_eq!(
+            cpu.last_executed(),
+            Some((0x204, Operation::LD1(LD1::new(0x3, 0x42))))
+        );
+    }
+
+    #[test]
+    fn test_self_modifying_write() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory
+            .load_rom(
+                &[
+                    assembler::ld1(0x0, 0x12),
+                    assembler::ldi(0x206),
+                    assembler::ldiv(0x0),
+                ]
+                .concat(),
+            )
+            .unwrap();
+
+        // Act
+        for _ in 0..3 {
+            execute(&mut cpu, &mut register, &mut memory).unwrap();
+        }
+
+        // Assert
+        assert_eq!(memory.get_byte(0x206), 0x12);
+        assert_eq!(cpu.diagnostics().self_modifying_writes(), 1);
+    }
+
+    #[test]
+    fn test_self_modifying_write_far() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory
+            .load_rom(
+                &[
+                    assembler::ld1(0x0, 0x12),
+                    assembler::ldi(0x300),
+                    assembler::ldiv(0x0),
+                ]
+                .concat(),
+            )
+            .unwrap();
+
+        // Act
+        for _ in 0..3 {
+            execute(&mut cpu, &mut register, &mut memory).unwrap();
+        }
+
+        // Assert
+        assert_eq!(memory.get_byte(0x300), 0x12);
+        assert_eq!(cpu.diagnostics().self_modifying_writes(), 0);
+    }
+
+    #[test]
+    fn test_reserved_fetch() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_font(&Font::new()).unwrap();
+        register.set_program_counter(0x060);
+
+        // Act
+        execute(&mut cpu, &mut register, &mut memory).unwrap();
+
+        // Assert
+        assert_eq!(cpu.diagnostics().reserved_fetches(), 1);
+    }
+
+    #[test]
+    fn test_illegal_instruction_warn() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_rom(&assembler::drw(0x0, 0x1, 0x0)).unwrap();
+        cpu.set_illegal_instruction_policy(Policy::Warn);
+
+        // Act
+        execute(&mut cpu, &mut register, &mut memory).unwrap();
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(cpu.diagnostics().illegal_instructions(), 1);
+    }
+
+    #[test]
+    fn test_illegal_instruction_trap() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_rom(&assembler::drw(0x0, 0x1, 0x0)).unwrap();
+        cpu.set_illegal_instruction_policy(Policy::Trap);
+
+        // Act
+        let result = execute(&mut cpu, &mut register, &mut memory);
+
+        // Assert
+        assert_eq!(result, Err(Fault::IllegalInstruction { address: 0x200 }));
+        assert_eq!(register.get_program_counter(), 0x200);
+        assert_eq!(cpu.last_executed(), None);
+    }
+
+    #[test]
+    fn test_illegal_instruction_superchip() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_rom(&assembler::scd(0x4)).unwrap();
+        cpu.set_illegal_instruction_policy(Policy::Trap);
+
+        // Act
+        let result = cpu.execute(
+            &mut register,
+            &mut Display::new(),
+            &Keypad::new(),
+            &mut memory,
+            &mut Timer::new(),
+            &mut Timer::new(),
+            Quirks::cosmac(),
+        );
+
+        // Assert
+        assert_eq!(result, Err(Fault::IllegalInstruction { address: 0x200 }));
+    }
+
+    #[test]
+    fn test_illegal_instruction_ignore() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_rom(&assembler::drw(0x0, 0x1, 0x0)).unwrap();
+        cpu.set_illegal_instruction_policy(Policy::Ignore);
+
+        // Act
+        execute(&mut cpu, &mut register, &mut memory).unwrap();
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(cpu.diagnostics().illegal_instructions(), 0);
+    }
+
+    #[test]
+    fn test_illegal_instruction_high_res() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        let mut display = Display::new();
+        memory
+            .load_rom(&[assembler::high(), assembler::drw(0x0, 0x1, 0x0)].concat())
+            .unwrap();
+        cpu.set_illegal_instruction_policy(Policy::Trap);
+
+        // Act
+        for _ in 0..2 {
+            cpu.execute(
+                &mut register,
+                &mut display,
+                &Keypad::new(),
+                &mut memory,
+                &mut Timer::new(),
+                &mut Timer::new(),
+                Quirks::default(),
+            )
+            .unwrap();
         }
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x204);
+        assert_eq!(cpu.diagnostics().illegal_instructions(), 0);
+    }
+
+    #[test]
+    fn test_self_recursive_call_trap() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_rom(&assembler::call(0x200)).unwrap();
+
+        // Act
+        let result = execute(&mut cpu, &mut register, &mut memory);
+
+        // Assert
+        assert_eq!(result, Err(Fault::SelfRecursiveCall { address: 0x200 }));
+        assert_eq!(register.get_program_counter(), 0x200);
+        assert!(register.get_stack().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_instruction() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_rom(&[0xFF, 0xFF]).unwrap();
+
+        // Act
+        let result = execute(&mut cpu, &mut register, &mut memory);
+
+        // Assert
+        assert_eq!(result, Err(Fault::UnknownInstruction { address: 0x200 }));
+        assert_eq!(register.get_program_counter(), 0x200);
+    }
+
+    #[test]
+    fn test_machine_code_routine() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_rom(&[0x00, 0x00]).unwrap();
+
+        // Act
+        let result = execute(&mut cpu, &mut register, &mut memory);
+
+        // Assert
+        assert_eq!(result, Err(Fault::MachineCodeRoutine { address: 0x200 }));
+        assert_eq!(register.get_program_counter(), 0x200);
+    }
+
+    #[test]
+    fn test_stack_overflow() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory
+            .load_rom(&[assembler::ld1(0x0, 0x01), assembler::call(0x200)].concat())
+            .unwrap();
+
+        // Act
+        let result = (0..100)
+            .map(|_| execute(&mut cpu, &mut register, &mut memory))
+            .find(Result::is_err);
+
+        // Assert
+        assert_eq!(result, Some(Err(Fault::StackOverflow { address: 0x202 })));
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.stack_depth(), 16);
+    }
+
+    #[test]
+    fn test_self_recursive_call_warn() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory
+            .load_rom(&[assembler::call(0x202), assembler::call(0x202)].concat())
+            .unwrap();
+        cpu.set_self_recursive_call_policy(Policy::Warn);
+
+        // Act
+        execute(&mut cpu, &mut register, &mut memory).unwrap();
+        execute(&mut cpu, &mut register, &mut memory).unwrap();
+
+        // Assert
+        assert_eq!(cpu.diagnostics().self_recursive_calls(), 1);
+        assert_eq!(register.get_stack().len(), 2);
+    }
+
+    #[test]
+    fn test_dispatch_and2() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_rom(&assembler::and2(0x4, 0x2)).unwrap();
+        register.set_v(0x4, 0b1100_1010);
+        register.set_v(0x2, 0b1010_0110);
+
+        // Act
+        execute(&mut cpu, &mut register, &mut memory).unwrap();
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0x4), 0b1000_0010);
+        assert_eq!(register.get_v(0xF), 0x0);
+    }
+
+    #[test]
+    fn test_dispatch_add2() {
+        // Arrange
+        let mut cpu = Cpu::new();
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        memory.load_rom(&assembler::add2(0x4, 0x2)).unwrap();
+        register.set_v(0x4, 0b1100_1010);
+        register.set_v(0x2, 0b1010_0110);
+
+        // Act
+        execute(&mut cpu, &mut register, &mut memory).unwrap();
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0x4), 0b0111_0000);
+        assert_eq!(register.get_v(0xF), 0x1);
     }
 }
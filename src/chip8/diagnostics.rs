@@ -0,0 +1,63 @@
+/// Counters of suspicious program behaviour, useful when debugging a ROM
+#[derive(Default)]
+pub struct Diagnostics {
+    illegal_instructions: u32,
+    reserved_fetches: u32,
+    self_modifying_writes: u32,
+    self_recursive_calls: u32,
+}
+
+/// Response to a suspicious instruction, such as one the current display mode or quirks do not support
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Execute the instruction as usual
+    Ignore,
+    /// Execute the instruction and count it in the diagnostics
+    Warn,
+    /// Stop the emulator before the instruction executes
+    Trap,
+}
+
+impl Diagnostics {
+    /// Number of executed instructions which the display mode at the time or the quirks did not support
+    #[must_use]
+    pub fn illegal_instructions(&self) -> u32 {
+        self.illegal_instructions
+    }
+
+    /// Number of instructions fetched from the interpreter's reserved memory below 0x200, which holds the font
+    #[must_use]
+    pub fn reserved_fetches(&self) -> u32 {
+        self.reserved_fetches
+    }
+
+    /// Number of instructions which wrote to memory near the instruction itself, ie. self-modifying code
+    #[must_use]
+    pub fn self_modifying_writes(&self) -> u32 {
+        self.self_modifying_writes
+    }
+
+    /// Number of executed instructions which called their own address
+    #[must_use]
+    pub fn self_recursive_calls(&self) -> u32 {
+        self.self_recursive_calls
+    }
+
+    pub(super) fn fetch(&mut self, program_counter: u16) {
+        if program_counter < 0x200 {
+            self.reserved_fetches = self.reserved_fetches.saturating_add(1);
+        }
+    }
+
+    pub(super) fn illegal_instruction(&mut self) {
+        self.illegal_instructions = self.illegal_instructions.saturating_add(1);
+    }
+
+    pub(super) fn self_recursive_call(&mut self) {
+        self.self_recursive_calls = self.self_recursive_calls.saturating_add(1);
+    }
+
+    pub(super) fn self_modifying_write(&mut self) {
+        self.self_modifying_writes = self.self_modifying_writes.saturating_add(1);
+    }
+}
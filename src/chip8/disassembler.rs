@@ -0,0 +1,47 @@
+use super::operation::Operation;
+
+/// Mnemonic of the instruction two bytes decode to, as written in Cowgod's Chip-8 Technical Reference.
+/// Bytes that don't decode, such as sprite data, come out as `UNKNOWN`.
+#[must_use]
+pub fn disassemble(bytes: [u8; 2]) -> String {
+    Operation::parse(bytes).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disassemble_ibm_logo() {
+        // Arrange
+        let rom = std::fs::read("./roms/IBM Logo.ch8").unwrap();
+
+        // Act
+        let disassembly = rom
+            .chunks_exact(2)
+            .take(9)
+            .map(|bytes| disassemble([bytes[0], bytes[1]]))
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(
+            disassembly,
+            vec![
+                "CLS",
+                "LD I, 0x22A",
+                "LD V0, 0x0C",
+                "LD V1, 0x08",
+                "DRW V0, V1, 0xF",
+                "ADD V0, 0x09",
+                "LD I, 0x239",
+                "DRW V0, V1, 0xF",
+                "LD I, 0x248",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disassemble_unknown() {
+        assert_eq!(disassemble([0xFF, 0xFF]), "UNKNOWN 0xFFFF");
+    }
+}
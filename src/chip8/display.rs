@@ -1,24 +1,325 @@
 pub(super) struct Display {
-    /// 64 x 32 pixels monochrome, ie. black or white
-    pixels: [[bool; 64]; 32],
+    /// Whether pixels were written since the renderer last took the flag
+    dirty: bool,
+    /// SUPER-CHIP 128 x 64 mode, low resolution only uses the top left 64 x 32 pixels
+    high_res: bool,
+    /// 128 x 64 pixels monochrome, ie. black or white
+    pixels: [[bool; 128]; 64],
 }
 
 impl Display {
+    pub(super) const WIDTH: u8 = 64;
+    pub(super) const HEIGHT: u8 = 32;
+    pub(super) const HIGH_RES_WIDTH: u8 = 128;
+    pub(super) const HIGH_RES_HEIGHT: u8 = 64;
+
     pub(super) fn new() -> Display {
         Display {
-            pixels: [[false; 64]; 32],
+            dirty: true,
+            high_res: false,
+            pixels: [[false; 128]; 64],
         }
     }
 
     pub(super) fn clear_screen(&mut self) {
-        self.pixels = [[false; 64]; 32];
+        self.pixels = [[false; 128]; 64];
+        self.dirty = true;
+    }
+
+    pub(super) fn is_high_res(&self) -> bool {
+        self.high_res
+    }
+
+    /// Switches between the 64 x 32 and 128 x 64 modes, clearing the screen
+    pub(super) fn set_high_res(&mut self, high_res: bool) {
+        self.high_res = high_res;
+        self.clear_screen();
+    }
+
+    /// Width of the active mode in pixels
+    pub(super) fn width(&self) -> u8 {
+        if self.high_res {
+            Display::HIGH_RES_WIDTH
+        } else {
+            Display::WIDTH
+        }
+    }
+
+    /// Height of the active mode in pixels
+    pub(super) fn height(&self) -> u8 {
+        if self.high_res {
+            Display::HIGH_RES_HEIGHT
+        } else {
+            Display::HEIGHT
+        }
     }
 
+    /// Pixels outside the active mode are always off
     pub(super) fn is_pixel_on(&self, x: u8, y: u8) -> bool {
-        self.pixels[y as usize][x as usize]
+        x < self.width() && y < self.height() && self.pixels[y as usize][x as usize]
     }
 
     pub(super) fn set_pixel(&mut self, x: u8, y: u8, value: bool) {
+        assert!(
+            x < self.width() && y < self.height(),
+            "pixel {}, {} is outside the display",
+            x,
+            y
+        );
         self.pixels[y as usize][x as usize] = value;
+        self.dirty = true;
+    }
+
+    /// Whether pixels were written since the last call
+    pub(super) fn take_dirty(&mut self) -> bool {
+        std::mem::replace(&mut self.dirty, false)
+    }
+
+    /// Every pixel as rows from the top, only the active mode's top left corner is ever on
+    pub(super) fn buffer(&self) -> &[[bool; 128]; 64] {
+        &self.pixels
+    }
+
+    /// Rows of pixels from the top as bits, the most significant bit is the leftmost pixel
+    pub(super) fn to_bits(&self) -> Vec<u128> {
+        self.pixels
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .fold(0, |bits, pixel| bits << 1 | u128::from(*pixel))
+            })
+            .collect()
+    }
+
+    /// Restores the mode and pixels saved by [`Display::to_bits`]
+    pub(super) fn load_bits(&mut self, high_res: bool, rows: &[u128]) {
+        self.high_res = high_res;
+        for (row, bits) in self.pixels.iter_mut().zip(rows) {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                *pixel = bits >> (127 - x) & 1 == 1;
+            }
+        }
+        self.dirty = true;
+    }
+
+    /// Pixels of the first 64 columns of a row as bits, the most significant bit is the leftmost pixel
+    #[cfg(test)]
+    pub(super) fn get_row(&self, y: u8) -> u64 {
+        self.pixels[y as usize][..64]
+            .iter()
+            .fold(0, |bits, pixel| bits << 1 | u64::from(*pixel))
+    }
+
+    /// Sets the pixels of the first 64 columns of a row from bits, the most significant bit is the leftmost pixel
+    #[cfg(test)]
+    pub(super) fn set_row(&mut self, y: u8, bits: u64) {
+        for (x, pixel) in self.pixels[y as usize][..64].iter_mut().enumerate() {
+            *pixel = bits >> (63 - x) & 1 == 1;
+        }
+    }
+}
+
+/// SUPER-CHIP scrolling, vacated rows and columns are turned off
+impl Display {
+    /// Scrolls n pixels down
+    pub(super) fn scroll_down(&mut self, n: u8) {
+        let rows = usize::from(self.height());
+        let n = usize::from(n).min(rows);
+        self.pixels.copy_within(..rows - n, n);
+        self.pixels[..n].fill([false; 128]);
+        self.dirty = true;
+    }
+
+    /// Scrolls n pixels up, XO-CHIP's 00DN which no instruction decodes yet
+    #[allow(dead_code)]
+    pub(super) fn scroll_up(&mut self, n: u8) {
+        let rows = usize::from(self.height());
+        let n = usize::from(n).min(rows);
+        self.pixels.copy_within(n..rows, 0);
+        self.pixels[rows - n..rows].fill([false; 128]);
+        self.dirty = true;
+    }
+
+    /// Scrolls 4 pixels left
+    pub(super) fn scroll_left(&mut self) {
+        let columns = usize::from(self.width());
+        for row in &mut self.pixels {
+            row.copy_within(4..columns, 0);
+            row[columns - 4..columns].fill(false);
+        }
+        self.dirty = true;
+    }
+
+    /// Scrolls 4 pixels right
+    pub(super) fn scroll_right(&mut self) {
+        let columns = usize::from(self.width());
+        for row in &mut self.pixels {
+            row.copy_within(..columns - 4, 4);
+            row[..4].fill(false);
+        }
+        self.dirty = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATTERN: u64 = 0xF00F_0000_0000_A005;
+
+    #[test]
+    fn test_row() {
+        // Arrange
+        let mut display = Display::new();
+
+        // Act
+        display.set_row(3, PATTERN);
+
+        // Assert
+        assert_eq!(display.get_row(3), PATTERN);
+        assert_eq!(display.get_row(2), 0);
+        assert!(display.is_pixel_on(0, 3));
+        assert!(!display.is_pixel_on(4, 3));
+        assert!(display.is_pixel_on(63, 3));
+        assert!(!display.is_pixel_on(62, 3));
+    }
+
+    #[test]
+    fn test_scroll_down() {
+        // Arrange
+        let mut display = Display::new();
+        display.set_row(0, PATTERN);
+        display.set_row(30, PATTERN);
+
+        // Act
+        display.scroll_down(2);
+
+        // Assert
+        assert_eq!(display.get_row(0), 0);
+        assert_eq!(display.get_row(1), 0);
+        assert_eq!(display.get_row(2), PATTERN);
+        assert_eq!(display.get_row(30), 0);
+        assert_eq!(display.get_row(31), 0);
+    }
+
+    #[test]
+    fn test_scroll_up() {
+        // Arrange
+        let mut display = Display::new();
+        display.set_row(1, PATTERN);
+        display.set_row(31, PATTERN);
+
+        // Act
+        display.scroll_up(2);
+
+        // Assert
+        assert_eq!(display.get_row(0), 0);
+        assert_eq!(display.get_row(1), 0);
+        assert_eq!(display.get_row(29), PATTERN);
+        assert_eq!(display.get_row(30), 0);
+        assert_eq!(display.get_row(31), 0);
+    }
+
+    #[test]
+    fn test_scroll_past_edge() {
+        // Arrange
+        let mut display = Display::new();
+        display.set_row(0, PATTERN);
+        display.set_row(31, PATTERN);
+
+        // Act
+        display.scroll_down(0xFF);
+        let down = (0..Display::HEIGHT)
+            .map(|y| display.get_row(y))
+            .collect::<Vec<_>>();
+        display.set_row(0, PATTERN);
+        display.scroll_up(Display::HEIGHT);
+        let up = (0..Display::HEIGHT)
+            .map(|y| display.get_row(y))
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(down, vec![0; 32]);
+        assert_eq!(up, vec![0; 32]);
+    }
+
+    #[test]
+    fn test_scroll_left() {
+        // Arrange
+        let mut display = Display::new();
+        display.set_row(0, PATTERN);
+        display.set_row(31, u64::MAX);
+
+        // Act
+        display.scroll_left();
+
+        // Assert
+        assert_eq!(display.get_row(0), 0x00F0_0000_000A_0050);
+        assert_eq!(display.get_row(31), 0xFFFF_FFFF_FFFF_FFF0);
+    }
+
+    #[test]
+    fn test_scroll_right() {
+        // Arrange
+        let mut display = Display::new();
+        display.set_row(0, PATTERN);
+        display.set_row(31, u64::MAX);
+
+        // Act
+        display.scroll_right();
+
+        // Assert
+        assert_eq!(display.get_row(0), 0x0F00_F000_0000_0A00);
+        assert_eq!(display.get_row(31), 0x0FFF_FFFF_FFFF_FFFF);
+    }
+
+    #[test]
+    fn test_set_high_res() {
+        // Arrange
+        let mut display = Display::new();
+        display.set_pixel(63, 31, true);
+
+        // Act
+        display.set_high_res(true);
+
+        // Assert
+        assert!(display.is_high_res());
+        assert_eq!((display.width(), display.height()), (128, 64));
+        assert!(!display.is_pixel_on(63, 31), "switching clears the screen");
+
+        // Act
+        display.set_pixel(127, 63, true);
+
+        // Assert
+        assert!(display.is_pixel_on(127, 63));
+
+        // Act
+        display.set_high_res(false);
+
+        // Assert
+        assert!(!display.is_high_res());
+        assert_eq!((display.width(), display.height()), (64, 32));
+        assert!(!display.is_pixel_on(127, 63));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the display")]
+    fn test_set_pixel_outside_low_res() {
+        Display::new().set_pixel(64, 0, true);
+    }
+
+    #[test]
+    fn test_scroll_left_high_res() {
+        // Arrange
+        let mut display = Display::new();
+        display.set_high_res(true);
+        display.set_pixel(127, 0, true);
+
+        // Act
+        display.scroll_left();
+
+        // Assert
+        assert!(display.is_pixel_on(123, 0));
+        assert!(!display.is_pixel_on(127, 0));
     }
 }
@@ -1,26 +1,122 @@
 use super::{
-    cpu::Cpu, display::Display, font::Font, keypad::Keypad, memory::Memory, register::Register,
-    timer::Timer,
+    cartridge::Cartridge,
+    cpu::{Cpu, Flow},
+    diagnostics::{Diagnostics, Policy},
+    display::Display,
+    fault::Fault,
+    font::Font,
+    input::InputSource,
+    keypad::Keypad,
+    memory::Memory,
+    operation::Operation,
+    profile::ProfileStats,
+    quirks::Quirks,
+    register::Register,
+    state::EmulatorState,
+    timer::{Frames, Timer},
+    warning::Warning,
+    watch::{WatchHit, WatchKind},
 };
 
-pub(crate) struct Emulator {
+pub struct Emulator {
+    /// Time left of the minimum beep started when the sound timer last started
+    beep_hold: std::time::Duration,
     beeping: bool,
+    breakpoints: std::collections::BTreeSet<u16>,
+    /// Breakpoint execution stopped at, its instruction runs when execution resumes
+    breakpoint_hit: Option<u16>,
     cpu: Cpu,
+    cycles: u64,
     delay_timer: Timer,
     display: Display,
     execute_interval: std::time::Duration,
+    framebuffer_origin: Origin,
+    /// Durations of the frames [`Emulator::tick_frames`] emulates
+    frames: Frames,
+    /// States before the most recent stepped instructions, oldest first, for stepping back
+    history: std::collections::VecDeque<EmulatorState>,
+    /// Stepped instructions [`Emulator::step_back`] can undo, zero keeps no history
+    history_depth: usize,
     keypad: Keypad,
+    /// DRW instructions executed a frame before the rest of the frame is skipped
+    max_draws_per_frame: Option<u32>,
     memory: Memory,
-    paused: bool,
+    min_beep_duration: std::time::Duration,
+    /// Whether loading a ROM leaves the display as the previous program left it
+    preserve_display: bool,
+    quirks: Quirks,
+    /// Display frames being recorded, from every call to [`Emulator::emulate`] while running
+    #[cfg(feature = "recording")]
+    recorder: Option<super::recording::GifRecorder>,
     register: Register,
+    /// Last loaded ROM, reloaded by a cold boot
+    rom: Option<Vec<u8>>,
+    /// What [`Emulator::analyze_rom`] found in the last loaded ROM
+    rom_warnings: Vec<Warning>,
     sound_timer: Timer,
+    /// Whether the sound timer was still running at the end of the last emulated frame
+    sounding: bool,
+    status: EmulatorStatus,
     time: std::time::Duration,
+    timing: Timing,
+    /// COSMAC VIP clock cycles multiplied by a billion available to execute with, negative while in debt
+    vip_credit: i128,
+    warmup_frames: u32,
+    /// Watched memory access the emulator paused after, until the next instruction executes
+    watch_hit: Option<WatchHit>,
+}
+
+/// Whether the emulator is executing instructions, and why not when it isn't
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmulatorStatus {
+    Running,
+    /// Waiting to be resumed, the state of a new emulator until a ROM is loaded
+    Paused,
+    /// The program jumped to itself and can make no further progress
+    Halted,
+    /// The program executed 00FD - EXIT
+    Exited,
+    /// The program hit a trap
+    Error(Fault),
+}
+
+/// Configures an [`Emulator`] before it starts
+pub struct EmulatorBuilder {
+    framebuffer_origin: Origin,
+    history_depth: usize,
+    max_draws_per_frame: Option<u32>,
+    max_stack_depth: usize,
+    min_beep_duration: std::time::Duration,
+    paused: bool,
+    preserve_display: bool,
+    quirks: Quirks,
+    timing: Timing,
+    trace_depth: usize,
+    warmup_frames: u32,
+}
+
+/// How fast instructions execute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timing {
+    /// 700 instructions a second, whatever they are
+    Flat,
+    /// Instructions take as long as they did on a 1.76 MHz COSMAC VIP, at most one sprite is drawn a frame
+    Vip,
+}
+
+/// Corner of the display the first framebuffer row starts from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    /// Rows run top to bottom, as CHIP-8 addresses the display
+    TopLeft,
+    /// Rows run bottom to top, as most graphics APIs address textures
+    BottomLeft,
 }
 
 #[cfg(feature = "editor")]
 pub(crate) struct Debug {
     pub(crate) delay_timer: u8,
-    pub(crate) memory_ram: Vec<u8>,
     pub(crate) register_i: u16,
     pub(crate) register_program_counter: u16,
     pub(crate) register_stack: Vec<u16>,
@@ -29,113 +125,772 @@ pub(crate) struct Debug {
 }
 
 impl Emulator {
-    pub(crate) fn new() -> Emulator {
+    /// # Panics
+    ///
+    /// Will panic if the font does not fit in the interpreter's reserved memory.
+    #[must_use]
+    pub fn new() -> Emulator {
         let mut emulator = Emulator {
+            beep_hold: std::time::Duration::ZERO,
             beeping: false,
+            breakpoints: std::collections::BTreeSet::new(),
+            breakpoint_hit: None,
             cpu: Cpu::new(),
+            cycles: 0,
             delay_timer: Timer::new(),
             display: Display::new(),
             execute_interval: std::time::Duration::from_secs(1) / 700,
+            framebuffer_origin: Origin::TopLeft,
+            frames: Frames::new(60),
+            history: std::collections::VecDeque::new(),
+            history_depth: 0,
             keypad: Keypad::new(),
+            max_draws_per_frame: None,
             memory: Memory::new(),
-            paused: true,
+            min_beep_duration: std::time::Duration::ZERO,
+            preserve_display: false,
+            quirks: Quirks::default(),
+            #[cfg(feature = "recording")]
+            recorder: None,
             register: Register::new(),
+            rom: None,
+            rom_warnings: Vec::new(),
             sound_timer: Timer::new(),
+            sounding: false,
+            status: EmulatorStatus::Paused,
             time: std::time::Duration::ZERO,
+            timing: Timing::Flat,
+            vip_credit: 0,
+            warmup_frames: 0,
+            watch_hit: None,
         };
 
         emulator
             .memory
-            .load_font(Font::new().data())
+            .load_font(&Font::new())
             .expect("failed to load font");
 
         emulator
     }
 
-    pub(crate) fn emulate(&mut self, delta: &std::time::Duration) {
-        if self.paused {
+    #[must_use]
+    pub fn builder() -> EmulatorBuilder {
+        EmulatorBuilder::new()
+    }
+
+    /// Default emulator with the ROM loaded, ready to run
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the ROM does not fit in memory.
+    pub fn with_rom(rom: &[u8]) -> crate::Result<Emulator> {
+        let mut emulator = Emulator::builder().build();
+        emulator.load_rom(rom)?;
+        Ok(emulator)
+    }
+
+    pub fn emulate(&mut self, delta: &std::time::Duration) {
+        if self.status != EmulatorStatus::Running {
+            self.beep_hold = std::time::Duration::ZERO;
+            self.beeping = false;
             return;
         }
 
-        let b1 = self.sound_timer.get();
+        self.keypad.tick(delta);
+
+        let b1 = self.sound_timer.value();
         self.delay_timer.tick(delta);
         self.sound_timer.tick(delta);
-        let b2 = self.sound_timer.get();
+        let b2 = self.sound_timer.value();
 
-        if b2 > 0 && b1 != b2 {
-            self.beeping = true;
-        } else {
-            self.beeping = false;
+        // a sound timer set to 1 or 2 can expire before it is ever seen changing
+        if b1 > 0 && !self.sounding {
+            self.beep_hold = self.min_beep_duration;
+        }
+        self.sounding = b2 > 0;
+
+        // frames shorter than a 60 Hz tick leave the timer unchanged, the beep carries on through them
+        self.beeping = b2 > 0 || !self.beep_hold.is_zero();
+        self.beep_hold = self.beep_hold.saturating_sub(*delta);
+
+        self.cpu.reset_draws();
+
+        match self.timing {
+            Timing::Flat => self.execute_flat(delta),
+            Timing::Vip => self.execute_vip(delta),
         }
 
-        let current_time = self.time;
-        let target_time = self.time.saturating_add(*delta);
+        #[cfg(feature = "recording")]
+        if let Some(recorder) = &mut self.recorder {
+            let size = (self.display.width(), self.display.height());
+            recorder.push(self.display.buffer(), size, *delta);
+        }
+    }
 
-        let current_executions = current_time.as_micros() / self.execute_interval.as_micros();
-        let target_executions = target_time.as_micros() / self.execute_interval.as_micros();
-        let delta_executions = target_executions - current_executions;
+    fn execute_flat(&mut self, delta: &std::time::Duration) {
+        let (target_time, delta_executions) = advance(self.time, *delta, self.execute_interval);
 
         for _ in 0..delta_executions {
-            self.cpu.execute(
-                &mut self.register,
-                &mut self.display,
-                &self.keypad,
-                &mut self.memory,
-                &mut self.delay_timer,
-                &mut self.sound_timer,
-            );
+            if self.at_breakpoint() {
+                break;
+            }
+            self.execute();
+
+            if self.status != EmulatorStatus::Running || self.draw_limit_reached() {
+                break;
+            }
         }
 
         self.time = target_time;
     }
 
-    pub(crate) fn is_beeping(&self) -> bool {
+    fn execute_vip(&mut self, delta: &std::time::Duration) {
+        /// Clock speed of the COSMAC VIP's CDP1802
+        const CLOCK_HZ: i128 = 1_760_900;
+
+        self.vip_credit += i128::try_from(delta.as_nanos()).unwrap_or(i128::MAX) * CLOCK_HZ;
+
+        while self.vip_credit > 0 && self.status == EmulatorStatus::Running {
+            if self.at_breakpoint() {
+                break;
+            }
+            let machine_cycles =
+                Cpu::decode(Cpu::fetch(&self.memory, &self.register)).machine_cycles();
+            self.vip_credit -= i128::from(machine_cycles) * 8 * 1_000_000_000;
+            self.execute();
+
+            if self.draw_limit_reached() {
+                // the skipped instructions aren't owed to the next frame
+                self.vip_credit = self.vip_credit.min(0);
+                break;
+            }
+        }
+    }
+
+    /// Pauses when the program counter reaches a breakpoint,
+    /// except the one execution stopped at last so resuming gets past it
+    fn at_breakpoint(&mut self) -> bool {
+        let program_counter = self.register.get_program_counter();
+
+        if self.breakpoint_hit.take() == Some(program_counter)
+            || !self.breakpoints.contains(&program_counter)
+        {
+            return false;
+        }
+
+        self.breakpoint_hit = Some(program_counter);
+        self.status = EmulatorStatus::Paused;
+        true
+    }
+
+    fn draw_limit_reached(&self) -> bool {
+        let draws = self.cpu.draws();
+
+        (self.quirks.display_wait && draws >= 1)
+            || matches!(self.max_draws_per_frame, Some(max) if draws >= max)
+    }
+
+    /// Returns `false` if there already was a breakpoint at the address
+    pub fn add_breakpoint(&mut self, program_counter: u16) -> bool {
+        self.breakpoints.insert(program_counter)
+    }
+
+    /// Program counters to stop at, in ascending order
+    #[must_use]
+    pub fn breakpoints(&self) -> &std::collections::BTreeSet<u16> {
+        &self.breakpoints
+    }
+
+    /// Breakpoint the emulator paused at, until execution moves on
+    #[must_use]
+    pub fn breakpoint_hit(&self) -> Option<u16> {
+        self.breakpoint_hit
+    }
+
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+    }
+
+    /// Address, opcode and mnemonic of `count` instructions from `start`, which may be odd.
+    /// Bytes that don't decode, such as sprite data, are listed as `UNKNOWN`.
+    #[must_use]
+    pub fn disassemble_range(&self, start: u16, count: usize) -> Vec<(u16, u16, String)> {
+        use super::disassembler::disassemble;
+
+        (0..count)
+            .scan(start & 0x0FFF, |address, _| {
+                let current = *address;
+                *address = address.wrapping_add(2) & 0x0FFF;
+                Some(current)
+            })
+            .map(|address| {
+                let bytes = [
+                    self.memory.get_byte(address),
+                    self.memory.get_byte(address.wrapping_add(1) & 0x0FFF),
+                ];
+                (address, u16::from_be_bytes(bytes), disassemble(bytes))
+            })
+            .collect()
+    }
+
+    /// Address and mnemonic of the most recently executed instruction
+    #[must_use]
+    pub fn last_executed(&self) -> Option<(u16, String)> {
+        self.cpu
+            .last_executed()
+            .map(|(address, operation)| (address, operation.to_string()))
+    }
+
+    /// Addresses and mnemonics of the most recently executed instructions, oldest first,
+    /// as many as [`EmulatorBuilder::trace_depth`] keeps
+    #[must_use]
+    pub fn last_instructions(&self) -> Vec<(u16, String)> {
+        self.cpu
+            .trace()
+            .map(|(address, operation)| (address, operation.to_string()))
+            .collect()
+    }
+
+    /// Instructions executed a second with [`Timing::Flat`]
+    #[must_use]
+    pub fn clock_hz(&self) -> u32 {
+        u32::try_from(1_000_000 / self.execute_interval.as_micros()).unwrap_or(u32::MAX)
+    }
+
+    /// Bytes of the last loaded ROM, as [`Emulator::reset_cold`] reloads them
+    #[must_use]
+    pub fn current_rom(&self) -> Option<&[u8]> {
+        self.rom.as_deref()
+    }
+
+    /// `rows` bytes the next DRW would read, starting at I
+    ///
+    /// Reads past 0xFFF wrap to 0x000 with [`Quirks::wrap_sprite_reads`] and repeat the byte at
+    /// 0xFFF without it, reads past the ROM are 0 with [`Quirks::zero_reads_past_rom`].
+    #[must_use]
+    pub fn current_sprite(&self, rows: u8) -> Vec<u8> {
+        use super::operation::DRW;
+
+        (0..rows)
+            .map(|row| DRW::sprite_byte(&self.memory, self.register.get_i(), row, self.quirks))
+            .collect()
+    }
+
+    /// Instructions executed since the emulator was created
+    #[must_use]
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
+
+    #[must_use]
+    pub fn diagnostics(&self) -> &Diagnostics {
+        self.cpu.diagnostics()
+    }
+
+    /// Reason execution stopped, if the program hit a trap
+    #[must_use]
+    pub fn fault(&self) -> Option<Fault> {
+        match self.status {
+            EmulatorStatus::Error(fault) => Some(fault),
+            _ => None,
+        }
+    }
+
+    /// Every pixel as rows from the top, `true` when on, whatever the framebuffer origin
+    ///
+    /// Only the top left [`framebuffer_size`](Emulator::framebuffer_size) pixels are used, the rest are off.
+    #[must_use]
+    pub fn framebuffer(&self) -> &[[bool; 128]; 64] {
+        self.display.buffer()
+    }
+
+    /// Whether the display was drawn to since the last call, so renderers can skip unchanged frames
+    pub fn take_display_dirty(&mut self) -> bool {
+        self.display.take_dirty()
+    }
+
+    /// Corner the framebuffer rows start from
+    #[must_use]
+    pub fn framebuffer_origin(&self) -> Origin {
+        self.framebuffer_origin
+    }
+
+    /// Display as rows of RGBA pixels from the framebuffer origin, white when on and black when off
+    #[must_use]
+    pub fn framebuffer_rgba(&self) -> Vec<u8> {
+        let (width, height) = self.framebuffer_size();
+        let origin = self.framebuffer_origin;
+
+        (0..height)
+            .map(|row| match origin {
+                Origin::TopLeft => row,
+                Origin::BottomLeft => height - row - 1,
+            })
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .flat_map(|(x, y)| {
+                if self.is_pixel_on(x, y) {
+                    [0xFF, 0xFF, 0xFF, 0xFF]
+                } else {
+                    [0x00, 0x00, 0x00, 0xFF]
+                }
+            })
+            .collect()
+    }
+
+    /// Display as text for terminal dumps, a line per row from the top whatever the origin
+    #[must_use]
+    pub fn framebuffer_ascii(&self, on: char, off: char) -> String {
+        let (width, height) = self.framebuffer_size();
+
+        (0..height)
+            .flat_map(|y| {
+                (0..width)
+                    .map(move |x| if self.is_pixel_on(x, y) { on } else { off })
+                    .chain(std::iter::once('\n'))
+            })
+            .collect()
+    }
+
+    /// Display as text using Unicode half blocks, a line per two rows so pixels come out square
+    #[must_use]
+    pub fn framebuffer_half_blocks(&self) -> String {
+        let (width, height) = self.framebuffer_size();
+
+        (0..height)
+            .step_by(2)
+            .flat_map(|y| {
+                (0..width)
+                    .map(move |x| {
+                        let top = self.is_pixel_on(x, y);
+                        let bottom = y + 1 < height && self.is_pixel_on(x, y + 1);
+
+                        match (top, bottom) {
+                            (true, true) => '█',
+                            (true, false) => '▀',
+                            (false, true) => '▄',
+                            (false, false) => ' ',
+                        }
+                    })
+                    .chain(std::iter::once('\n'))
+            })
+            .collect()
+    }
+
+    /// PNG of the display with every pixel drawn as a `scale` x `scale` square of the on or off RGB color
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the image can't be encoded.
+    #[cfg(feature = "screenshot")]
+    pub fn screenshot(&self, scale: u32, on: [u8; 3], off: [u8; 3]) -> crate::Result<Vec<u8>> {
+        super::screenshot::encode_png(
+            self.display.buffer(),
+            self.framebuffer_size(),
+            scale,
+            on,
+            off,
+        )
+    }
+
+    /// Starts recording the display to a GIF, discarding any recording already in progress
+    #[cfg(feature = "recording")]
+    pub fn start_recording(&mut self) {
+        self.recorder = Some(super::recording::GifRecorder::new());
+    }
+
+    /// Stops recording and encodes what was recorded, a GIF without frames if nothing was
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the GIF can't be encoded.
+    #[cfg(feature = "recording")]
+    pub fn stop_recording(&mut self) -> crate::Result<Vec<u8>> {
+        self.recorder.take().unwrap_or_default().finish()
+    }
+
+    #[cfg(feature = "recording")]
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Width and height of the display in pixels, 128 x 64 in SUPER-CHIP's high resolution mode
+    #[must_use]
+    pub fn framebuffer_size(&self) -> (u8, u8) {
+        (self.display.width(), self.display.height())
+    }
+
+    #[must_use]
+    pub fn illegal_instruction_policy(&self) -> Policy {
+        self.cpu.illegal_instruction_policy()
+    }
+
+    #[must_use]
+    pub fn self_recursive_call_policy(&self) -> Policy {
+        self.cpu.self_recursive_call_policy()
+    }
+
+    #[must_use]
+    pub fn is_beeping(&self) -> bool {
         self.beeping
     }
 
-    pub(crate) fn is_pixel_on(&self, x: u8, y: u8) -> bool {
+    /// Whether the program switched to SUPER-CHIP's 128 x 64 display
+    #[must_use]
+    pub fn is_high_res(&self) -> bool {
+        self.display.is_high_res()
+    }
+
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.status == EmulatorStatus::Paused
+    }
+
+    #[must_use]
+    pub fn is_pixel_on(&self, x: u8, y: u8) -> bool {
         self.display.is_pixel_on(x, y)
     }
 
-    pub(crate) fn key_pressed(&mut self, key: super::keypad::Key) {
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.status == EmulatorStatus::Running
+    }
+
+    pub fn key_pressed(&mut self, key: super::keypad::Key) {
         self.keypad.pressed(key);
     }
 
-    pub(crate) fn key_released(&mut self, key: super::keypad::Key) {
+    pub fn key_released(&mut self, key: super::keypad::Key) {
         self.keypad.released(key);
     }
 
-    pub(crate) fn load_rom(&mut self, rom: &[u8]) -> crate::Result<()> {
-        self.paused = false;
-        self.memory.load_rom(rom)
+    /// Presses or releases a key, for frontends whose key events carry the state
+    pub fn key_event(&mut self, key: super::keypad::Key, pressed: bool) {
+        if pressed {
+            self.key_pressed(key);
+        } else {
+            self.key_released(key);
+        }
+    }
+
+    /// Reads the keys from a frontend's input, call it once a frame before [`Emulator::emulate`]
+    pub fn poll_input(&mut self, input: &mut impl InputSource) {
+        self.keypad.poll(input);
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the ROM does not fit in memory.
+    pub fn load_cartridge(&mut self, cartridge: &Cartridge) -> crate::Result<()> {
+        self.quirks = cartridge.quirks();
+        self.load_rom(cartridge.rom())
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the ROM does not fit in memory.
+    pub fn load_rom(&mut self, rom: &[u8]) -> crate::Result<()> {
+        // a rejected ROM leaves the emulator as it was
+        self.memory.load_rom(rom)?;
+        self.status = EmulatorStatus::Running;
+        self.rom = Some(rom.to_vec());
+        self.rom_warnings = self.analyze_rom(rom);
+        if !self.preserve_display {
+            self.display.clear_screen();
+        }
+
+        // skips boot animations, nothing is drawn or played until the frames have run
+        self.tick_frames(self.warmup_frames);
+        self.beep_hold = std::time::Duration::ZERO;
+        self.beeping = false;
+
+        Ok(())
+    }
+
+    /// Instructions in the ROM which might misbehave with the emulator's configuration, the first of each kind
+    ///
+    /// SUPER-CHIP instructions are reported when the quirks don't enable them, and Dxy0 when they do
+    /// but the ROM never switches to the 128 x 64 display it needs.
+    ///
+    /// Every pair of bytes is read as an instruction, so sprite data can be mistaken for one.
+    #[must_use]
+    pub fn analyze_rom(&self, rom: &[u8]) -> Vec<Warning> {
+        let instructions: Vec<(u16, Operation)> = (0x200..)
+            .step_by(2)
+            .zip(rom.chunks_exact(2))
+            .map(|(address, bytes)| (address, Operation::parse([bytes[0], bytes[1]])))
+            .collect();
+        let mut warnings = Vec::new();
+
+        if !self.quirks.superchip_instructions {
+            let mut seen = Vec::new();
+            for (address, instruction) in &instructions {
+                let kind = std::mem::discriminant(instruction);
+                if instruction.is_superchip() && !seen.contains(&kind) {
+                    seen.push(kind);
+                    warnings.push(Warning::SuperChipInstruction { address: *address });
+                }
+            }
+        } else if !instructions
+            .iter()
+            .any(|(_, instruction)| matches!(instruction, Operation::HIGH(_)))
+        {
+            if let Some((address, _)) = instructions
+                .iter()
+                .find(|(_, instruction)| instruction.requires_high_res())
+            {
+                warnings.push(Warning::HighResWithoutSwitch { address: *address });
+            }
+        }
+
+        warnings
+    }
+
+    /// Warnings [`Emulator::analyze_rom`] gave for the last loaded ROM
+    #[must_use]
+    pub fn rom_warnings(&self) -> &[Warning] {
+        &self.rom_warnings
+    }
+
+    /// Restores a snapshot taken by [`Emulator::save_state`], keeping the emulator's configuration
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the state's memory is not the emulator's 4 kilobytes.
+    pub fn load_state(&mut self, state: &EmulatorState) {
+        self.cpu.set_pending_key(state.pending_key);
+        self.cycles = state.cycles;
+        self.delay_timer = state.delay_timer.clone();
+        self.display.load_bits(state.high_res, &state.display);
+        self.memory.load_ram(&state.ram, state.rom_end);
+        self.register = state.register.clone();
+        self.register.set_v_registers(state.register_v);
+        self.sound_timer = state.sound_timer.clone();
+        self.status = state.status;
+        self.time = state.time;
+        self.vip_credit = state.vip_credit;
+    }
+
+    /// Totals since profiling was first enabled, all zero while it never was
+    #[must_use]
+    pub fn profile(&self) -> ProfileStats {
+        self.cpu.profile()
+    }
+
+    /// Bytes of RAM from `start`, shorter than `len` when it runs past the end of memory
+    #[must_use]
+    pub fn memory_slice(&self, start: u16, len: u16) -> &[u8] {
+        self.memory.get_slice(start, len)
+    }
+
+    /// Power cycles the emulator, clearing RAM and reloading the font and the last loaded ROM
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the font does not fit in the interpreter's reserved memory.
+    pub fn reset_cold(&mut self) {
+        self.reset_warm();
+
+        let watchpoints = self.memory.watchpoints().clone();
+        self.memory = Memory::new();
+        self.memory.set_watchpoints(watchpoints);
+        self.memory
+            .load_font(&Font::new())
+            .expect("failed to load font");
+
+        if let Some(rom) = self.rom.take() {
+            self.load_rom(&rom).expect("failed to reload rom");
+        }
+    }
+
+    /// Resets the registers, timers and display but leaves RAM as it is, for debugging self-modifying programs
+    pub fn reset_warm(&mut self) {
+        self.beep_hold = std::time::Duration::ZERO;
+        self.beeping = false;
+        self.breakpoint_hit = None;
+        self.cpu.reset_pending_key();
+        self.delay_timer = Timer::new();
+        self.display = Display::new();
+        self.history.clear();
+        self.register = Register::new();
+        self.sound_timer = Timer::new();
+        self.sounding = false;
+        self.status = if self.rom.is_some() {
+            EmulatorStatus::Running
+        } else {
+            EmulatorStatus::Paused
+        };
+        self.time = std::time::Duration::ZERO;
+        self.vip_credit = 0;
+        self.watch_hit = None;
+    }
+
+    /// Returns `false` if there was no breakpoint at the address
+    pub fn remove_breakpoint(&mut self, program_counter: u16) -> bool {
+        self.breakpoints.remove(&program_counter)
+    }
+
+    /// Snapshot of the running program, for quick saves
+    #[must_use]
+    pub fn save_state(&self) -> EmulatorState {
+        EmulatorState {
+            cycles: self.cycles,
+            delay_timer: self.delay_timer.clone(),
+            display: self.display.to_bits(),
+            high_res: self.display.is_high_res(),
+            pending_key: self.cpu.pending_key(),
+            ram: self.memory.ram().into(),
+            register: self.register.clone(),
+            register_v: self.register.v_registers(),
+            rom_end: self.memory.rom_end(),
+            sound_timer: self.sound_timer.clone(),
+            status: self.status,
+            time: self.time,
+            vip_credit: self.vip_credit,
+        }
+    }
+
+    /// Times instruction decoding and execution, off by default as timing every instruction has a cost
+    ///
+    /// Has no effect on wasm32, which has no clock to time with.
+    pub fn set_profiling(&mut self, profiling: bool) {
+        self.cpu.set_profiling(profiling);
+    }
+
+    /// Releases keys which aren't pressed again within the duration, for frontends without key up events
+    pub fn set_key_auto_release(&mut self, auto_release: Option<std::time::Duration>) {
+        self.keypad.set_auto_release(auto_release);
+    }
+
+    /// Accessibility mode, a key press latches the key until it is pressed again
+    pub fn set_sticky_keys(&mut self, sticky: bool) {
+        self.keypad.set_sticky(sticky);
+    }
+
+    /// Instructions to execute a second with [`Timing::Flat`], defaults to 700,
+    /// clamped to 1 Hz through 1 MHz as execution is scheduled in whole microseconds
+    pub fn set_clock_hz(&mut self, hz: u32) {
+        self.execute_interval = std::time::Duration::from_secs(1) / hz.clamp(1, 1_000_000);
+    }
+
+    pub fn set_framebuffer_origin(&mut self, origin: Origin) {
+        self.framebuffer_origin = origin;
+    }
+
+    /// A paused emulator neither executes instructions nor ticks its timers,
+    /// has no effect once the program halted, exited or faulted
+    pub fn set_paused(&mut self, paused: bool) {
+        match (self.status, paused) {
+            (EmulatorStatus::Running, true) => self.status = EmulatorStatus::Paused,
+            (EmulatorStatus::Paused, false) => self.status = EmulatorStatus::Running,
+            _ => (),
+        }
+    }
+
+    /// Pauses a running emulator and resumes a paused one, see [`Emulator::set_paused`]
+    pub fn toggle_pause(&mut self) {
+        self.set_paused(!self.is_paused());
+    }
+
+    /// Sets the whole keypad at once, bit `n` of the mask being key `n`
+    pub fn set_keys(&mut self, mask: u16) {
+        self.keypad.set_keys(mask);
+    }
+
+    pub fn set_illegal_instruction_policy(&mut self, policy: Policy) {
+        self.cpu.set_illegal_instruction_policy(policy);
+    }
+
+    /// Response to a CALL of its own address, defaults to [`Policy::Trap`] as it can only recurse forever
+    pub fn set_self_recursive_call_policy(&mut self, policy: Policy) {
+        self.cpu.set_self_recursive_call_policy(policy);
+    }
+
+    /// Overwrites a byte of RAM anywhere in memory, for patching a program while debugging it.
+    /// The address wraps at 4 kilobytes, the program isn't seen modifying itself and watchpoints don't trip.
+    pub fn set_ram_byte(&mut self, address: u16, byte: u8) {
+        self.memory.poke(address, byte);
+    }
+
+    /// Overwrites the index register, for poking at a program while debugging it
+    pub fn set_i(&mut self, i: u16) {
+        self.register.set_i(i);
+    }
+
+    /// Overwrites register `Vx`, for poking at a program while debugging it
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `x` is not a register, `0x0` through `0xF`.
+    pub fn set_v(&mut self, x: u8, nn: u8) {
+        self.register.set_v(x, nn);
+    }
+
+    #[must_use]
+    pub fn status(&self) -> EmulatorStatus {
+        self.status
+    }
+
+    /// Emulates whole 60 Hz frames, independent of wall clock time
+    pub fn tick_frames(&mut self, frames: u32) {
+        for _ in 0..frames {
+            let frame = self.frames.next_frame();
+            self.emulate(&frame);
+        }
     }
 
     #[cfg(feature = "editor")]
     pub(crate) fn get_debug(&self) -> Debug {
         Debug {
-            delay_timer: self.delay_timer.get(),
-            memory_ram: self.memory.get_ram().into(),
+            delay_timer: self.delay_timer.value(),
             register_i: self.register.get_i(),
             register_program_counter: self.register.get_program_counter(),
             register_stack: self.register.get_stack().into(),
-            register_v: (0..=0xF)
-                .into_iter()
-                .map(|x| self.register.get_v(x))
-                .collect(),
-            sound_timer: self.sound_timer.get(),
+            register_v: self.register.v_registers().to_vec(),
+            sound_timer: self.sound_timer.value(),
         }
     }
 
+    /// Rows of 16 bytes of RAM written since the last call, keyed by their address
+    #[allow(clippy::cast_possible_truncation)]
     #[cfg(feature = "editor")]
-    pub(crate) fn step_execute(&mut self) {
-        self.cpu.execute(
-            &mut self.register,
-            &mut self.display,
-            &self.keypad,
-            &mut self.memory,
-            &mut self.delay_timer,
-            &mut self.sound_timer,
-        );
+    pub(crate) fn take_dirty_memory(&mut self) -> Vec<(u16, Vec<u8>)> {
+        let rows = self.memory.take_dirty_rows();
+
+        rows.into_iter()
+            .map(|row| row as u16 * 0x10)
+            .map(|address| (address, self.memory_slice(address, 0x10).into()))
+            .collect()
+    }
+
+    /// Instruction at the program counter, the one [`Emulator::step_execute`] runs next
+    #[cfg(feature = "editor")]
+    pub(crate) fn next_instruction(&self) -> String {
+        Cpu::decode(Cpu::fetch(&self.memory, &self.register)).to_string()
+    }
+
+    /// Undoes the most recent stepped instruction, returns `false` if there is no history left
+    pub fn step_back(&mut self) -> bool {
+        match self.history.pop_back() {
+            Some(state) => {
+                self.load_state(&state);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Executes the instruction at the program counter, even while paused, for debuggers to drive
+    pub fn step_execute(&mut self) {
+        if self.history_depth > 0 {
+            if self.history.len() == self.history_depth {
+                self.history.pop_front();
+            }
+            self.history.push_back(self.save_state());
+        }
+
+        self.breakpoint_hit = None;
+        self.execute();
     }
 
     #[cfg(feature = "editor")]
@@ -147,4 +902,2095 @@ impl Emulator {
     pub(crate) fn zero_sound(&mut self) {
         self.sound_timer.set(0);
     }
+
+    /// Pauses after the next instruction that accesses the address as the kind watches for,
+    /// replacing any watchpoint already on it
+    pub fn watch(&mut self, address: u16, kind: WatchKind) {
+        self.memory.watch(address, kind);
+    }
+
+    /// Returns `false` if the address was not watched
+    pub fn unwatch(&mut self, address: u16) -> bool {
+        self.memory.unwatch(address)
+    }
+
+    /// Watched addresses, in ascending order
+    #[must_use]
+    pub fn watchpoints(&self) -> &std::collections::BTreeMap<u16, WatchKind> {
+        self.memory.watchpoints()
+    }
+
+    pub fn clear_watchpoints(&mut self) {
+        self.memory.clear_watchpoints();
+    }
+
+    /// Watched memory access the emulator paused after, until the next instruction executes
+    #[must_use]
+    pub fn watch_hit(&self) -> Option<WatchHit> {
+        self.watch_hit
+    }
+
+    /// Executes a single instruction, stopping the emulator when the program can't continue
+    fn execute(&mut self) {
+        let program_counter = self.register.get_program_counter();
+        self.watch_hit = None;
+
+        match self.cpu.execute(
+            &mut self.register,
+            &mut self.display,
+            &self.keypad,
+            &mut self.memory,
+            &mut self.delay_timer,
+            &mut self.sound_timer,
+            self.quirks,
+        ) {
+            Ok(flow) => {
+                self.cycles += 1;
+
+                match flow {
+                    Flow::Continue => (),
+                    Flow::Halt => self.status = EmulatorStatus::Halted,
+                    Flow::Exit => self.status = EmulatorStatus::Exited,
+                }
+            }
+            Err(fault) => self.status = EmulatorStatus::Error(fault),
+        }
+
+        if let Some((address, access)) = self.memory.take_watch_hit() {
+            self.watch_hit = Some(WatchHit {
+                address,
+                access,
+                program_counter,
+            });
+            self.set_paused(true);
+        }
+    }
+}
+
+/// Time after `delta` more has elapsed and the number of `interval`s completed on the way,
+/// time left over from an incomplete interval counts towards the next call's
+fn advance(
+    time: std::time::Duration,
+    delta: std::time::Duration,
+    interval: std::time::Duration,
+) -> (std::time::Duration, u128) {
+    let target_time = time.saturating_add(delta);
+
+    // an interval under a microsecond would divide by zero
+    let interval = interval.as_micros().max(1);
+    let current_executions = time.as_micros() / interval;
+    let target_executions = target_time.as_micros() / interval;
+
+    (target_time, target_executions - current_executions)
+}
+
+impl Default for Emulator {
+    fn default() -> Emulator {
+        Emulator::new()
+    }
+}
+
+impl Default for EmulatorBuilder {
+    fn default() -> EmulatorBuilder {
+        EmulatorBuilder::new()
+    }
+}
+
+impl EmulatorBuilder {
+    #[must_use]
+    pub fn new() -> EmulatorBuilder {
+        EmulatorBuilder {
+            framebuffer_origin: Origin::TopLeft,
+            history_depth: 0,
+            max_draws_per_frame: None,
+            max_stack_depth: 16,
+            min_beep_duration: std::time::Duration::ZERO,
+            paused: true,
+            preserve_display: false,
+            quirks: Quirks::default(),
+            timing: Timing::Flat,
+            trace_depth: 0,
+            warmup_frames: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn build(self) -> Emulator {
+        let mut emulator = Emulator::new();
+        emulator.status = if self.paused {
+            EmulatorStatus::Paused
+        } else {
+            EmulatorStatus::Running
+        };
+        emulator.framebuffer_origin = self.framebuffer_origin;
+        emulator.history_depth = self.history_depth;
+        emulator.max_draws_per_frame = self.max_draws_per_frame;
+        emulator.cpu.set_max_stack_depth(self.max_stack_depth);
+        emulator.min_beep_duration = self.min_beep_duration;
+        emulator.preserve_display = self.preserve_display;
+        emulator.quirks = self.quirks;
+        emulator.timing = self.timing;
+        emulator.cpu.set_trace_depth(self.trace_depth);
+        emulator.warmup_frames = self.warmup_frames;
+        emulator
+    }
+
+    /// Corner the framebuffer rows start from, defaults to [`Origin::TopLeft`]
+    #[must_use]
+    pub fn framebuffer_origin(mut self, origin: Origin) -> EmulatorBuilder {
+        self.framebuffer_origin = origin;
+        self
+    }
+
+    /// Stepped instructions [`Emulator::step_back`] can undo, the oldest are forgotten first,
+    /// defaults to zero which keeps no history
+    #[must_use]
+    pub fn history_depth(mut self, depth: usize) -> EmulatorBuilder {
+        self.history_depth = depth;
+        self
+    }
+
+    /// DRW instructions executed a frame before execution waits for the next one, spares the renderer
+    /// from programs that flicker by redrawing many times a frame, defaults to no limit
+    #[must_use]
+    pub fn max_draws_per_frame(mut self, draws: Option<u32>) -> EmulatorBuilder {
+        self.max_draws_per_frame = draws;
+        self
+    }
+
+    /// Levels of the stack, a CALL with every level in use stops the emulator with
+    /// [`Fault::StackOverflow`], defaults to 16 like most interpreters since the COSMAC VIP's 12
+    #[must_use]
+    pub fn max_stack_depth(mut self, depth: usize) -> EmulatorBuilder {
+        self.max_stack_depth = depth;
+        self
+    }
+
+    /// Interpreter behaviours to emulate, replaced by the cartridge's when one is loaded
+    #[must_use]
+    pub fn quirks(mut self, quirks: Quirks) -> EmulatorBuilder {
+        self.quirks = quirks;
+        self
+    }
+
+    /// Shortest time [`Emulator::is_beeping`] reports a beep for after the sound timer starts,
+    /// so sound timer values of 1 or 2 are still audible, defaults to zero
+    #[must_use]
+    pub fn min_beep_duration(mut self, duration: std::time::Duration) -> EmulatorBuilder {
+        self.min_beep_duration = duration;
+        self
+    }
+
+    /// Whether loading a ROM leaves the display as it was instead of clearing it, defaults to `false`
+    #[must_use]
+    pub fn preserve_display(mut self, preserve: bool) -> EmulatorBuilder {
+        self.preserve_display = preserve;
+        self
+    }
+
+    /// Whether execution waits for a ROM to be loaded, defaults to `true`
+    #[must_use]
+    pub fn start_paused(mut self, paused: bool) -> EmulatorBuilder {
+        self.paused = paused;
+        self
+    }
+
+    /// How fast instructions execute, defaults to [`Timing::Flat`]
+    #[must_use]
+    pub fn timing(mut self, timing: Timing) -> EmulatorBuilder {
+        self.timing = timing;
+        self
+    }
+
+    /// Executed instructions [`Emulator::last_instructions`] remembers, the oldest are forgotten first,
+    /// defaults to zero which keeps no trace
+    #[must_use]
+    pub fn trace_depth(mut self, depth: usize) -> EmulatorBuilder {
+        self.trace_depth = depth;
+        self
+    }
+
+    /// 60 Hz frames to run as fast as possible as soon as a ROM is loaded, defaults to 0
+    #[must_use]
+    pub fn warmup_frames(mut self, frames: u32) -> EmulatorBuilder {
+        self.warmup_frames = frames;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{assembler, input::KeyEvent, keypad::Key},
+        *,
+    };
+
+    #[test]
+    fn test_breakpoints() {
+        // Arrange
+        let mut emulator = Emulator::new();
+
+        // Act & Assert
+        assert!(emulator.add_breakpoint(0x204));
+        assert!(emulator.add_breakpoint(0x200));
+        assert!(emulator.add_breakpoint(0x202));
+        assert_eq!(
+            emulator.breakpoints().iter().copied().collect::<Vec<_>>(),
+            vec![0x200, 0x202, 0x204]
+        );
+
+        assert!(emulator.remove_breakpoint(0x202));
+        assert_eq!(
+            emulator.breakpoints().iter().copied().collect::<Vec<_>>(),
+            vec![0x200, 0x204]
+        );
+
+        assert!(!emulator.remove_breakpoint(0x202));
+        assert_eq!(emulator.breakpoints().len(), 2);
+    }
+
+    #[test]
+    fn test_breakpoints_duplicate() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator.add_breakpoint(0x200);
+
+        // Act
+        let added = emulator.add_breakpoint(0x200);
+
+        // Assert
+        assert!(!added);
+        assert_eq!(
+            emulator.breakpoints().iter().copied().collect::<Vec<_>>(),
+            vec![0x200]
+        );
+    }
+
+    #[test]
+    fn test_clear_breakpoints() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator.add_breakpoint(0x200);
+        emulator.add_breakpoint(0x300);
+
+        // Act
+        emulator.clear_breakpoints();
+
+        // Assert
+        assert!(emulator.breakpoints().is_empty());
+    }
+
+    #[test]
+    fn test_breakpoint_pauses_ibm_logo() {
+        // Arrange
+        let rom = std::fs::read("./roms/IBM Logo.ch8").unwrap();
+        let mut emulator = Emulator::with_rom(&rom).unwrap();
+        emulator.add_breakpoint(0x208);
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Paused);
+        assert_eq!(emulator.breakpoint_hit(), Some(0x208));
+        assert_eq!(emulator.register.get_program_counter(), 0x208);
+        assert_eq!(emulator.cycles(), 4);
+        assert!(!emulator.is_pixel_on(12, 8), "DRW not executed");
+
+        // Act
+        emulator.set_paused(false);
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.breakpoint_hit(), None);
+        assert!(emulator.is_pixel_on(12, 8));
+    }
+
+    #[test]
+    fn test_breakpoint_survives_reset() {
+        // Arrange
+        let rom = [assembler::add1(0x0, 0x01), assembler::jp(0x200)].concat();
+        let mut emulator = Emulator::with_rom(&rom).unwrap();
+        emulator.add_breakpoint(0x202);
+        emulator.tick_frames(1);
+        assert_eq!(emulator.breakpoint_hit(), Some(0x202));
+
+        // Act
+        emulator.reset_cold();
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.breakpoint_hit(), Some(0x202));
+        assert_eq!(emulator.register.get_v(0x0), 0x01);
+    }
+
+    #[test]
+    fn test_watch_write() {
+        // Arrange
+        let rom = [
+            assembler::ld1(0x0, 0x42),
+            assembler::ldi(0x300),
+            assembler::add1(0x1, 0x01),
+            assembler::ldiv(0x0),
+            assembler::jp(0x204),
+        ]
+        .concat();
+        let mut emulator = Emulator::with_rom(&rom).unwrap();
+        emulator.watch(0x300, WatchKind::Write);
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Paused);
+        assert_eq!(
+            emulator.watch_hit(),
+            Some(WatchHit {
+                address: 0x300,
+                access: WatchKind::Write,
+                program_counter: 0x206,
+            })
+        );
+        assert_eq!(emulator.register.get_program_counter(), 0x208);
+        assert_eq!(emulator.memory.get_byte(0x300), 0x42);
+    }
+
+    #[test]
+    fn test_watch_read_sprite() {
+        // Arrange
+        let rom = [
+            assembler::ldi(0x050),
+            assembler::add1(0x1, 0x01),
+            assembler::drw(0x0, 0x0, 0x5),
+            assembler::jp(0x202),
+        ]
+        .concat();
+        let mut emulator = Emulator::with_rom(&rom).unwrap();
+        emulator.watch(0x054, WatchKind::ReadWrite);
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(
+            emulator.watch_hit(),
+            Some(WatchHit {
+                address: 0x054,
+                access: WatchKind::Read,
+                program_counter: 0x204,
+            })
+        );
+        assert_eq!(emulator.cycles(), 3);
+    }
+
+    #[test]
+    fn test_watchpoints_survive_reset() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator.watch(0x300, WatchKind::Read);
+
+        // Act
+        emulator.reset_cold();
+
+        // Assert
+        assert_eq!(emulator.watchpoints().get(&0x300), Some(&WatchKind::Read));
+        assert!(emulator.unwatch(0x300));
+        assert!(emulator.watchpoints().is_empty());
+    }
+
+    #[test]
+    fn test_set_registers() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(&[assembler::addi(0x3)].concat()).unwrap();
+
+        // Act
+        emulator.set_i(0x300);
+        emulator.set_v(0x3, 0x42);
+        emulator.step_execute();
+
+        // Assert
+        assert_eq!(emulator.register.get_v(0x3), 0x42);
+        assert_eq!(emulator.register.get_i(), 0x342);
+    }
+
+    #[test]
+    fn test_set_ram_byte() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(&[assembler::cls()].concat()).unwrap();
+        emulator.watch(0x300, WatchKind::Write);
+
+        // Act
+        emulator.set_ram_byte(0x300, 0x42);
+        emulator.set_ram_byte(0x1FFF, 0x24);
+        emulator.step_execute();
+
+        // Assert
+        assert_eq!(emulator.memory_slice(0x300, 1), &[0x42]);
+        assert_eq!(emulator.memory_slice(0xFFF, 1), &[0x24]);
+        assert_eq!(emulator.watch_hit(), None);
+        assert_eq!(emulator.diagnostics().self_modifying_writes(), 0);
+    }
+
+    #[test]
+    fn test_with_rom() {
+        // Arrange
+        let rom = std::fs::read("./roms/IBM Logo.ch8").unwrap();
+        let mut emulator = Emulator::with_rom(&rom).unwrap();
+
+        // Act
+        emulator.emulate(&(std::time::Duration::from_secs(1) / 700));
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Running);
+        assert_eq!(emulator.cycles(), 1);
+        assert_eq!(emulator.register.get_program_counter(), 0x202);
+        assert_eq!(emulator.last_executed(), Some((0x200, "CLS".to_string())));
+    }
+
+    #[test]
+    fn test_with_rom_too_large() {
+        assert!(Emulator::with_rom(&[0xFF; 0xE01]).is_err());
+    }
+
+    #[test]
+    fn test_unknown_instruction() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator
+            .load_rom(&[assembler::ld1(0x0, 0x01), [0xFF, 0xFF]].concat())
+            .unwrap();
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(
+            emulator.status(),
+            EmulatorStatus::Error(Fault::UnknownInstruction { address: 0x202 })
+        );
+        assert_eq!(emulator.register.get_v(0x0), 0x01);
+    }
+
+    #[test]
+    fn test_run_into_zeroed_memory() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator.load_rom(&assembler::ld1(0x0, 0x01)).unwrap();
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(
+            emulator.status(),
+            EmulatorStatus::Error(Fault::MachineCodeRoutine { address: 0x202 })
+        );
+        assert_eq!(emulator.register.get_v(0x0), 0x01);
+    }
+
+    #[test]
+    fn test_load_rom_too_large() {
+        // Arrange
+        let mut emulator = Emulator::new();
+
+        // Act
+        let result = emulator.load_rom(&[0xFF; 0xE01]);
+        emulator.emulate(&std::time::Duration::from_secs(1));
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(emulator.status(), EmulatorStatus::Paused);
+        assert_eq!(emulator.cycles(), 0);
+    }
+
+    #[test]
+    fn test_advance() {
+        use std::time::Duration;
+
+        let cases = [
+            // time, delta, interval, executions
+            (0, 1_000, 1_000, 1),
+            (0, 10_000, 1_000, 10),
+            (0, 999, 1_000, 0),
+            (999, 1, 1_000, 1),
+            (500, 1_000, 1_000, 1),
+            (500, 1_600, 1_000, 2),
+            (0, 16_666, 1_428, 11),
+            (16_666, 16_666, 1_428, 12),
+            (0, 0, 1_000, 0),
+            (0, 10, 0, 10),
+        ];
+
+        for (time, delta, interval, executions) in cases {
+            // Act
+            let (new_time, actual) = advance(
+                Duration::from_micros(time),
+                Duration::from_micros(delta),
+                Duration::from_micros(interval),
+            );
+
+            // Assert
+            assert_eq!(new_time, Duration::from_micros(time + delta));
+            assert_eq!(
+                actual, executions,
+                "{} + {} every {}",
+                time, delta, interval
+            );
+        }
+    }
+
+    #[test]
+    fn test_advance_accumulates() {
+        use std::time::Duration;
+
+        // Arrange
+        let interval = Duration::from_secs(1) / 700;
+        let mut time = Duration::ZERO;
+        let mut executions = 0;
+
+        // Act
+        for _ in 0..700 {
+            let (new_time, delta_executions) = advance(time, Duration::from_millis(1), interval);
+            time = new_time;
+            executions += delta_executions;
+        }
+
+        // Assert
+        assert_eq!(executions, 490);
+    }
+
+    #[test]
+    fn test_disassemble_range_ibm_logo() {
+        // Arrange
+        let mut rom = Vec::new();
+        let mut file = std::fs::File::open("./roms/IBM Logo.ch8").unwrap();
+        std::io::Read::read_to_end(&mut file, &mut rom).unwrap();
+        let emulator = Emulator::with_rom(&rom).unwrap();
+
+        // Act
+        let disassembly = emulator.disassemble_range(0x200, 6);
+
+        // Assert
+        assert_eq!(
+            disassembly,
+            vec![
+                (0x200, 0x00E0, "CLS".to_string()),
+                (0x202, 0xA22A, "LD I, 0x22A".to_string()),
+                (0x204, 0x600C, "LD V0, 0x0C".to_string()),
+                (0x206, 0x6108, "LD V1, 0x08".to_string()),
+                (0x208, 0xD01F, "DRW V0, V1, 0xF".to_string()),
+                (0x20A, 0x7009, "ADD V0, 0x09".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_last_instructions_ibm_logo() {
+        // Arrange
+        let rom = std::fs::read("./roms/IBM Logo.ch8").unwrap();
+        let mut emulator = Emulator::builder().trace_depth(4).build();
+        emulator.load_rom(&rom).unwrap();
+
+        // Act
+        for _ in 0..5 {
+            emulator.step_execute();
+        }
+
+        // Assert
+        assert_eq!(
+            emulator.last_instructions(),
+            vec![
+                (0x202, "LD I, 0x22A".to_string()),
+                (0x204, "LD V0, 0x0C".to_string()),
+                (0x206, "LD V1, 0x08".to_string()),
+                (0x208, "DRW V0, V1, 0xF".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_last_instructions_without_trace() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(&[assembler::cls()].concat()).unwrap();
+
+        // Act
+        emulator.step_execute();
+
+        // Assert
+        assert!(emulator.last_instructions().is_empty());
+    }
+
+    #[test]
+    fn test_disassemble_range_unaligned() {
+        // Arrange
+        let emulator = Emulator::with_rom(&[0x00, 0xE0, 0xFF, 0xFF, 0xFF]).unwrap();
+
+        // Act
+        let disassembly = emulator.disassemble_range(0x201, 2);
+
+        // Assert
+        assert_eq!(
+            disassembly,
+            vec![
+                (0x201, 0xE0FF, "UNKNOWN 0xE0FF".to_string()),
+                (0x203, 0xFFFF, "UNKNOWN 0xFFFF".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disassemble_range_wraps() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator.memory.set_byte(0xFFF, 0x00);
+        emulator.memory.set_byte(0x000, 0xE0);
+
+        // Act
+        let disassembly = emulator.disassemble_range(0xFFF, 2);
+
+        // Assert
+        assert_eq!(disassembly[0], (0xFFF, 0x00E0, "CLS".to_string()));
+        assert_eq!(disassembly[1].0, 0x001);
+    }
+
+    #[test]
+    fn test_set_clock_hz() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator
+            .load_rom(&[assembler::add1(0x0, 0x01), assembler::jp(0x200)].concat())
+            .unwrap();
+
+        // Act
+        emulator.set_clock_hz(1000);
+        for _ in 0..60 {
+            emulator.emulate(&(std::time::Duration::from_secs(1) / 60));
+        }
+
+        // Assert
+        assert_eq!(emulator.clock_hz(), 1000);
+        assert!(
+            (995..=1000).contains(&emulator.cycles()),
+            "{}",
+            emulator.cycles()
+        );
+    }
+
+    #[test]
+    fn test_set_clock_hz_zero() {
+        // Arrange
+        let mut emulator = Emulator::new();
+
+        // Act
+        emulator.set_clock_hz(0);
+        emulator.emulate(&std::time::Duration::from_secs(1));
+
+        // Assert
+        assert_eq!(emulator.clock_hz(), 1);
+    }
+
+    #[test]
+    fn test_set_clock_hz_max() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator
+            .load_rom(&[assembler::add1(0x0, 0x01), assembler::jp(0x200)].concat())
+            .unwrap();
+
+        // Act
+        emulator.set_clock_hz(u32::MAX);
+        emulator.emulate(&std::time::Duration::from_micros(1));
+
+        // Assert
+        assert_eq!(emulator.clock_hz(), 1_000_000);
+        assert_eq!(emulator.register.get_program_counter(), 0x202);
+    }
+
+    #[test]
+    fn test_emulate_trap() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator.set_illegal_instruction_policy(Policy::Trap);
+        emulator
+            .load_rom(&[assembler::ld1(0x0, 0x01), assembler::drw(0x0, 0x0, 0x0)].concat())
+            .unwrap();
+
+        // Act
+        emulator.emulate(&std::time::Duration::from_secs(1));
+
+        // Assert
+        assert_eq!(
+            emulator.fault(),
+            Some(Fault::IllegalInstruction { address: 0x202 })
+        );
+        assert_eq!(emulator.register.get_program_counter(), 0x202);
+        assert_eq!(emulator.register.get_v(0x0), 0x01);
+        assert_eq!(
+            emulator.status(),
+            EmulatorStatus::Error(Fault::IllegalInstruction { address: 0x202 })
+        );
+    }
+
+    #[test]
+    fn test_status_running() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        assert_eq!(emulator.status(), EmulatorStatus::Paused);
+
+        // Act
+        emulator
+            .load_rom(&[assembler::ld1(0x0, 0x01), assembler::add1(0x0, 0x01)].concat())
+            .unwrap();
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Running);
+        assert!(emulator.is_running());
+
+        // Act
+        emulator.set_paused(true);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Paused);
+        assert!(!emulator.is_running());
+    }
+
+    #[test]
+    fn test_toggle_pause() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator
+            .load_rom(&[assembler::add1(0x0, 0x01), assembler::jp(0x200)].concat())
+            .unwrap();
+
+        // Act
+        emulator.toggle_pause();
+        emulator.tick_frames(1);
+
+        // Assert
+        assert!(emulator.is_paused());
+        assert_eq!(emulator.register.get_program_counter(), 0x200);
+        assert_eq!(emulator.cycles(), 0);
+
+        // Act
+        emulator.toggle_pause();
+        emulator.tick_frames(1);
+
+        // Assert
+        assert!(!emulator.is_paused());
+        assert!(emulator.cycles() > 0);
+        assert_eq!(emulator.register.get_v(0x0), 6, "12 instructions a frame");
+    }
+
+    #[test]
+    fn test_step_execute_while_paused() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator
+            .load_rom(&[assembler::ld1(0x0, 0x01), assembler::ld1(0x1, 0x02)].concat())
+            .unwrap();
+        emulator.set_paused(true);
+
+        // Act
+        emulator.step_execute();
+
+        // Assert
+        assert!(emulator.is_paused());
+        assert_eq!(emulator.register.get_program_counter(), 0x202);
+        assert_eq!(emulator.register.get_v(0x0), 0x01);
+        assert_eq!(emulator.register.get_v(0x1), 0x00);
+    }
+
+    #[test]
+    fn test_status_halted() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator
+            .load_rom(&[assembler::ld1(0x0, 0x01), assembler::jp(0x202)].concat())
+            .unwrap();
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Halted);
+        assert_eq!(emulator.register.get_program_counter(), 0x202);
+
+        // Act
+        emulator.set_paused(false);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Halted);
+    }
+
+    #[test]
+    fn test_status_exited() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator
+            .load_rom(
+                &[
+                    assembler::ld1(0x0, 0x01),
+                    assembler::exit(),
+                    assembler::ld1(0x0, 0x02),
+                ]
+                .concat(),
+            )
+            .unwrap();
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Exited);
+        assert_eq!(emulator.register.get_program_counter(), 0x202);
+        assert_eq!(emulator.register.get_v(0x0), 0x01);
+    }
+
+    #[test]
+    fn test_start_paused() {
+        // Arrange
+        let mut emulator = Emulator::builder().start_paused(false).build();
+        let program = [assembler::ld1(0x0, 0x2A), assembler::jp(0x202)].concat();
+        for (offset, byte) in (0x200..).zip(program) {
+            emulator.memory.set_byte(offset, byte);
+        }
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.register.get_v(0x0), 0x2A);
+        assert_eq!(emulator.register.get_program_counter(), 0x202);
+    }
+
+    #[test]
+    fn test_start_paused_default() {
+        // Arrange
+        let mut emulator = Emulator::builder().build();
+        let program = [assembler::ld1(0x0, 0x2A), assembler::jp(0x202)].concat();
+        for (offset, byte) in (0x200..).zip(program) {
+            emulator.memory.set_byte(offset, byte);
+        }
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.register.get_v(0x0), 0x00);
+        assert_eq!(emulator.register.get_program_counter(), 0x200);
+
+        // Act
+        emulator.set_paused(false);
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.register.get_v(0x0), 0x2A);
+    }
+
+    #[test]
+    fn test_framebuffer_rgba_top_left() {
+        // Arrange
+        let mut emulator = Emulator::builder()
+            .framebuffer_origin(Origin::TopLeft)
+            .build();
+        emulator.display.set_pixel(0, 0, true);
+
+        // Act
+        let framebuffer = emulator.framebuffer_rgba();
+
+        // Assert
+        assert_eq!(framebuffer.len(), 64 * 32 * 4);
+        assert_eq!(framebuffer[..4], [0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(
+            framebuffer
+                .chunks(4)
+                .filter(|pixel| pixel[..3] == [0xFF, 0xFF, 0xFF])
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_framebuffer_rgba_bottom_left() {
+        // Arrange
+        let mut emulator = Emulator::builder()
+            .framebuffer_origin(Origin::BottomLeft)
+            .build();
+        emulator.display.set_pixel(0, 0, true);
+
+        // Act
+        let framebuffer = emulator.framebuffer_rgba();
+
+        // Assert
+        let offset = 31 * 64 * 4;
+        assert_eq!(framebuffer.len(), 64 * 32 * 4);
+        assert_eq!(framebuffer[offset..offset + 4], [0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(framebuffer[..4], [0x00, 0x00, 0x00, 0xFF]);
+        assert_eq!(
+            framebuffer
+                .chunks(4)
+                .filter(|pixel| pixel[..3] == [0xFF, 0xFF, 0xFF])
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_profile() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator
+            .load_rom(
+                &[
+                    assembler::ld1(0x0, 0x00),
+                    assembler::add1(0x0, 0x01),
+                    assembler::se1(0x0, 0xFF),
+                    assembler::jp(0x202),
+                    assembler::jp(0x208),
+                ]
+                .concat(),
+            )
+            .unwrap();
+        emulator.set_profiling(true);
+
+        // Act
+        emulator.tick_frames(120);
+
+        // Assert
+        let profile = emulator.profile();
+        assert_eq!(emulator.status(), EmulatorStatus::Halted);
+        // LD, then ADD, SE, JP until V0 is FF, then ADD, SE and the final JP
+        assert_eq!(profile.instructions, 1 + 0xFE * 3 + 2 + 1);
+        assert!(profile.parse > std::time::Duration::ZERO);
+        assert!(profile.execute > std::time::Duration::ZERO);
+        assert!(profile.parse + profile.execute < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_profile_disabled() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator
+            .load_rom(&[assembler::ld1(0x0, 0x01), assembler::jp(0x202)].concat())
+            .unwrap();
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.profile(), ProfileStats::default());
+    }
+
+    #[test]
+    fn test_warmup_frames() {
+        // Arrange
+        let mut emulator = Emulator::builder().warmup_frames(60).build();
+        let rom = [assembler::add1(0x0, 0x01), assembler::jp(0x200)].concat();
+
+        // Act
+        emulator.load_rom(&rom).unwrap();
+
+        // Assert
+        assert_eq!(emulator.cycles(), 700);
+        assert_eq!(emulator.status(), EmulatorStatus::Running);
+        assert_eq!(emulator.register.get_v(0x0), 0x5E);
+    }
+
+    #[test]
+    fn test_without_warmup_frames() {
+        // Arrange
+        let mut emulator = Emulator::builder().build();
+        let rom = [assembler::add1(0x0, 0x01), assembler::jp(0x200)].concat();
+
+        // Act
+        emulator.load_rom(&rom).unwrap();
+
+        // Assert
+        assert_eq!(emulator.cycles(), 0);
+        assert_eq!(emulator.register.get_v(0x0), 0x00);
+    }
+
+    #[test]
+    fn test_load_rom_clears_display() {
+        // Arrange
+        let mut emulator = Emulator::builder().build();
+        emulator.display.set_pixel(0, 0, true);
+        emulator.display.set_pixel(63, 31, true);
+
+        // Act
+        emulator
+            .load_rom(&[assembler::ld1(0x0, 0x01), assembler::jp(0x202)].concat())
+            .unwrap();
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.framebuffer_ascii('#', '.').matches('#').count(), 0);
+    }
+
+    #[test]
+    fn test_load_rom_preserve_display() {
+        // Arrange
+        let mut emulator = Emulator::builder().preserve_display(true).build();
+        emulator.display.set_pixel(0, 0, true);
+
+        // Act
+        emulator
+            .load_rom(&[assembler::ld1(0x0, 0x01), assembler::jp(0x202)].concat())
+            .unwrap();
+        emulator.tick_frames(1);
+
+        // Assert
+        assert!(emulator.is_pixel_on(0, 0));
+    }
+
+    #[test]
+    fn test_max_draws_per_frame() {
+        // Arrange
+        let mut emulator = Emulator::builder().max_draws_per_frame(Some(2)).build();
+        emulator
+            .load_rom(
+                &[
+                    assembler::ldi(0x050),
+                    assembler::drw(0x0, 0x0, 0x1),
+                    assembler::add1(0x0, 0x08),
+                    assembler::jp(0x202),
+                ]
+                .concat(),
+            )
+            .unwrap();
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.register.get_v(0x0), 0x08);
+        assert_eq!(emulator.register.get_program_counter(), 0x204);
+        assert!(emulator.is_pixel_on(0, 0));
+        assert!(emulator.is_pixel_on(8, 8));
+        assert!(!emulator.is_pixel_on(16, 16));
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.register.get_v(0x0), 0x18);
+        assert!(emulator.is_pixel_on(16, 16));
+    }
+
+    #[test]
+    fn test_max_stack_depth() {
+        // Arrange
+        let mut emulator = Emulator::builder().max_stack_depth(4).build();
+        emulator
+            .load_rom(&[assembler::ld1(0x0, 0x01), assembler::call(0x200)].concat())
+            .unwrap();
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(
+            emulator.status(),
+            EmulatorStatus::Error(Fault::StackOverflow { address: 0x202 })
+        );
+        assert_eq!(emulator.register.stack_depth(), 4);
+    }
+
+    #[test]
+    fn test_quirks_presets_shift() {
+        // Arrange
+        let rom = [
+            assembler::ld1(0x1, 0x10),
+            assembler::shr(0x0, 0x1),
+            assembler::jp(0x204),
+        ]
+        .concat();
+        let mut cosmac = Emulator::builder().quirks(Quirks::cosmac()).build();
+        let mut superchip = Emulator::builder().quirks(Quirks::superchip()).build();
+
+        // Act
+        for emulator in [&mut cosmac, &mut superchip] {
+            emulator.load_rom(&rom).unwrap();
+            emulator.tick_frames(1);
+        }
+
+        // Assert
+        assert_eq!(cosmac.register.get_v(0x0), 0x08, "shifted VY");
+        assert_eq!(superchip.register.get_v(0x0), 0x00, "shifted VX in place");
+    }
+
+    #[test]
+    fn test_quirks_presets_logic() {
+        // Arrange
+        let rom = [
+            assembler::ld1(0xF, 0x01),
+            assembler::xor(0x0, 0x1),
+            assembler::jp(0x204),
+        ]
+        .concat();
+        let mut default = Emulator::builder().build();
+        let mut cosmac = Emulator::builder().quirks(Quirks::cosmac()).build();
+
+        // Act
+        for emulator in [&mut default, &mut cosmac] {
+            emulator.load_rom(&rom).unwrap();
+            emulator.tick_frames(1);
+        }
+
+        // Assert
+        assert_eq!(default.register.get_v(0xF), 0x01, "left VF untouched");
+        assert_eq!(cosmac.register.get_v(0xF), 0x00, "reset VF");
+    }
+
+    #[test]
+    fn test_quirks_presets_display_wait() {
+        // Arrange
+        let rom = [
+            assembler::ldi(0x050),
+            assembler::drw(0x0, 0x0, 0x5),
+            assembler::add1(0x0, 0x08),
+            assembler::jp(0x202),
+        ]
+        .concat();
+        let mut cosmac = Emulator::builder().quirks(Quirks::cosmac()).build();
+        let mut superchip = Emulator::builder().quirks(Quirks::superchip()).build();
+
+        // Act
+        for emulator in [&mut cosmac, &mut superchip] {
+            emulator.load_rom(&rom).unwrap();
+            emulator.tick_frames(1);
+        }
+
+        // Assert
+        assert_eq!(
+            cosmac.register.get_v(0x0),
+            0x00,
+            "drew a sprite then waited"
+        );
+        assert!(superchip.register.get_v(0x0) > 0x08, "drew several sprites");
+    }
+
+    #[test]
+    fn test_quirks_display_wait() {
+        // Arrange
+        let rom = [
+            assembler::ldi(0x050),
+            assembler::drw(0x0, 0x1, 0x5),
+            assembler::add1(0x0, 0x08),
+            assembler::jp(0x202),
+        ]
+        .concat();
+        let mut emulator = Emulator::builder()
+            .quirks(Quirks {
+                display_wait: true,
+                ..Quirks::default()
+            })
+            .build();
+        emulator.load_rom(&rom).unwrap();
+
+        // Act
+        emulator.tick_frames(3);
+
+        // Assert
+        assert_eq!(
+            emulator.register.get_v(0x0),
+            0x10,
+            "drew a sprite a frame, moving between them"
+        );
+        assert!(emulator.is_pixel_on(0x10, 0), "third sprite");
+        assert!(!emulator.is_pixel_on(0x18, 0));
+    }
+
+    #[test]
+    fn test_analyze_rom_scroll() {
+        // Arrange
+        let rom = [
+            assembler::ldi(0x050),
+            assembler::scd(0x4),
+            assembler::ldhf(0x0),
+            assembler::scd(0x2),
+            assembler::jp(0x208),
+        ]
+        .concat();
+        let mut cosmac = Emulator::builder()
+            .quirks(Quirks {
+                shift_in_place: true,
+                ..Quirks::cosmac()
+            })
+            .build();
+        let default = Emulator::new();
+
+        // Act
+        cosmac.load_rom(&rom).unwrap();
+
+        // Assert
+        assert_eq!(
+            cosmac.rom_warnings(),
+            [
+                Warning::SuperChipInstruction { address: 0x202 },
+                Warning::SuperChipInstruction { address: 0x204 },
+            ],
+            "00CN and FX30, each once"
+        );
+        assert_eq!(default.analyze_rom(&rom), []);
+    }
+
+    #[test]
+    fn test_analyze_rom_high_res() {
+        // Arrange
+        let emulator = Emulator::new();
+        let rom = [assembler::drw(0x0, 0x1, 0x0), assembler::jp(0x202)].concat();
+
+        // Act
+        let warnings = emulator.analyze_rom(&rom);
+
+        // Assert
+        assert_eq!(warnings, [Warning::HighResWithoutSwitch { address: 0x200 }]);
+        assert_eq!(
+            emulator.analyze_rom(&[assembler::high(), assembler::drw(0x0, 0x1, 0x0)].concat()),
+            [],
+            "switches to the 128 x 64 display"
+        );
+    }
+
+    #[test]
+    fn test_memory_slice() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        let rom = [assembler::ld1(0x0, 0x12), assembler::jp(0x202)].concat();
+        emulator.load_rom(&rom).unwrap();
+
+        // Act
+        let slice = emulator.memory_slice(0x1FC, 0x10);
+
+        // Assert
+        assert_eq!(slice.len(), 0x10);
+        assert_eq!(&slice[4..8], &[0x60, 0x12, 0x12, 0x02]);
+        for (address, byte) in (0x1FC..).zip(slice) {
+            assert_eq!(*byte, emulator.memory.get_byte(address), "{:#05X}", address);
+        }
+    }
+
+    #[test]
+    fn test_memory_slice_bounds() {
+        let emulator = Emulator::new();
+
+        assert_eq!(emulator.memory_slice(0xFF8, 0x10).len(), 8);
+        assert_eq!(emulator.memory_slice(0x1000, 0x10).len(), 0);
+        assert_eq!(emulator.memory_slice(0xFFFF, 0xFFFF).len(), 0);
+        assert_eq!(emulator.memory_slice(0x000, 0xFFFF).len(), 0x1000);
+    }
+
+    #[test]
+    fn test_min_beep_duration() {
+        // Arrange
+        let mut emulator = Emulator::builder()
+            .min_beep_duration(std::time::Duration::from_millis(100))
+            .build();
+        let rom = [
+            assembler::ld1(0x0, 0x01),
+            assembler::ldst(0x0),
+            assembler::add1(0x1, 0x01),
+            assembler::jp(0x204),
+        ]
+        .concat();
+        emulator.load_rom(&rom).unwrap();
+        let frame = std::time::Duration::from_millis(16);
+
+        // Act
+        emulator.emulate(&frame);
+
+        // Assert
+        assert!(!emulator.is_beeping());
+
+        // Act & Assert
+        for tick in 0..7 {
+            emulator.emulate(&frame);
+            assert!(emulator.is_beeping(), "tick {}", tick);
+        }
+
+        // Act
+        emulator.emulate(&frame);
+
+        // Assert
+        assert!(!emulator.is_beeping());
+    }
+
+    #[test]
+    fn test_tick_frames_delay_timer() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator
+            .load_rom(
+                &[
+                    assembler::ld1(0x0, 60),
+                    assembler::lddtv(0x0),
+                    assembler::add1(0x1, 0x01),
+                    assembler::jp(0x204),
+                ]
+                .concat(),
+            )
+            .unwrap();
+        emulator.tick_frames(1);
+
+        // Act
+        emulator.tick_frames(59);
+
+        // Assert
+        assert_eq!(emulator.delay_timer.value(), 1);
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.delay_timer.value(), 0, "60 ticks in 60 frames");
+    }
+
+    #[test]
+    fn test_is_beeping_while_sound_timer_counts_down() {
+        // Arrange
+        let mut emulator = Emulator::builder().build();
+        let rom = [
+            assembler::ld1(0x0, 0x03),
+            assembler::ldst(0x0),
+            assembler::add1(0x1, 0x01),
+            assembler::jp(0x204),
+        ]
+        .concat();
+        emulator.load_rom(&rom).unwrap();
+        let frame = std::time::Duration::from_millis(7);
+        emulator.emulate(&frame);
+
+        // Act & Assert
+        for frame_number in 0..7 {
+            emulator.emulate(&frame);
+            assert!(emulator.is_beeping(), "frame {}", frame_number);
+        }
+
+        // Act
+        emulator.emulate(&frame);
+
+        // Assert
+        assert!(!emulator.is_beeping());
+    }
+
+    #[test]
+    fn test_is_beeping_until_sound_timer_reaches_zero() {
+        // Arrange
+        let mut emulator = Emulator::builder().build();
+        let rom = [
+            assembler::ld1(0x0, 0x02),
+            assembler::ldst(0x0),
+            assembler::add1(0x1, 0x01),
+            assembler::jp(0x204),
+        ]
+        .concat();
+        emulator.load_rom(&rom).unwrap();
+        let delta = std::time::Duration::from_millis(1);
+        while emulator.sound_timer.value() == 0 {
+            emulator.emulate(&delta);
+        }
+
+        // Act & Assert
+        let mut millis = 0;
+        loop {
+            emulator.emulate(&delta);
+            millis += 1;
+            if emulator.sound_timer.value() == 0 {
+                break;
+            }
+            assert!(emulator.is_beeping(), "{} ms", millis);
+        }
+
+        // Assert
+        assert_eq!(millis, 34, "2 ticks of 16.7 ms");
+        assert!(!emulator.is_beeping());
+    }
+
+    fn reset_rom() -> Vec<u8> {
+        [
+            assembler::ld1(0x0, 0x42),
+            assembler::ldi(0x300),
+            assembler::ldiv(0x0),
+            assembler::jp(0x206),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_current_rom() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        assert_eq!(emulator.current_rom(), None);
+
+        // Act
+        emulator.load_rom(&reset_rom()).unwrap();
+
+        // Assert
+        assert_eq!(emulator.current_rom(), Some(reset_rom().as_slice()));
+    }
+
+    #[test]
+    fn test_reset_cold() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(&reset_rom()).unwrap();
+        emulator.tick_frames(1);
+        assert_eq!(emulator.memory.get_byte(0x300), 0x42);
+
+        // Act
+        emulator.reset_cold();
+
+        // Assert
+        assert_eq!(emulator.memory.get_byte(0x300), 0x00);
+        assert_eq!(emulator.memory_slice(0x200, 8), reset_rom().as_slice());
+        assert_eq!(emulator.memory.get_byte(0x050), 0xF0);
+        assert_eq!(emulator.register.get_program_counter(), 0x200);
+        assert_eq!(emulator.register.get_v(0x0), 0x00);
+        assert_eq!(emulator.status(), EmulatorStatus::Running);
+    }
+
+    #[test]
+    fn test_reset_cold_clears_display() {
+        // Arrange
+        let rom = [
+            assembler::ldi(0x050),
+            assembler::drw(0x0, 0x0, 0x5),
+            assembler::add1(0x1, 0x01),
+            assembler::jp(0x204),
+        ]
+        .concat();
+        let mut emulator = Emulator::with_rom(&rom).unwrap();
+        emulator.tick_frames(1);
+        assert!(emulator.is_pixel_on(0, 0));
+
+        // Act
+        emulator.reset_cold();
+
+        // Assert
+        assert_eq!(emulator.register.get_program_counter(), 0x200);
+        assert!(emulator.framebuffer().iter().flatten().all(|pixel| !pixel));
+        assert_eq!(emulator.memory_slice(0x200, 8), rom.as_slice());
+    }
+
+    #[test]
+    fn test_reset_warm() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(&reset_rom()).unwrap();
+        emulator.tick_frames(1);
+        assert_eq!(emulator.status(), EmulatorStatus::Halted);
+
+        // Act
+        emulator.reset_warm();
+
+        // Assert
+        assert_eq!(emulator.memory.get_byte(0x300), 0x42);
+        assert_eq!(emulator.register.get_program_counter(), 0x200);
+        assert_eq!(emulator.register.get_v(0x0), 0x00);
+        assert_eq!(emulator.register.get_i(), 0x000);
+        assert_eq!(emulator.status(), EmulatorStatus::Running);
+    }
+
+    #[test]
+    fn test_timing() {
+        // Arrange
+        let rom = [assembler::add1(0x0, 0x01), assembler::jp(0x200)].concat();
+        let mut flat = Emulator::builder().timing(Timing::Flat).build();
+        flat.load_rom(&rom).unwrap();
+        let mut vip = Emulator::builder().timing(Timing::Vip).build();
+        vip.load_rom(&rom).unwrap();
+
+        // Act
+        flat.tick_frames(1);
+        vip.tick_frames(1);
+
+        // Assert
+        assert_eq!(flat.cycles(), 11);
+        assert_eq!(vip.cycles(), 223);
+    }
+
+    #[test]
+    fn test_timing_vip_draw() {
+        // Arrange
+        let rom = [assembler::drw(0x0, 0x0, 0x1), assembler::jp(0x200)].concat();
+        let mut flat = Emulator::builder().timing(Timing::Flat).build();
+        flat.load_rom(&rom).unwrap();
+        let mut vip = Emulator::builder().timing(Timing::Vip).build();
+        vip.load_rom(&rom).unwrap();
+
+        // Act
+        flat.tick_frames(60);
+        vip.tick_frames(60);
+
+        // Assert
+        assert_eq!(flat.cycles(), 700);
+        // a sprite and a jump take slightly longer than a frame
+        assert_eq!(vip.cycles(), 119);
+    }
+
+    /// Lights the top left corner like this, the rest of the display stays off
+    ///
+    /// ```text
+    /// #.#
+    /// ##.
+    /// .#.
+    /// ```
+    fn ascii_display() -> Emulator {
+        let mut emulator = Emulator::new();
+        for (x, y) in [(0, 0), (2, 0), (0, 1), (1, 1), (1, 2)] {
+            emulator.display.set_pixel(x, y, true);
+        }
+        emulator
+    }
+
+    #[test]
+    fn test_framebuffer() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(
+            &[
+                assembler::ld1(0x0, 0x3C),
+                assembler::ld1(0x1, 0x1B),
+                assembler::ld1(0x2, 0x8),
+                assembler::ldf(0x2),
+                assembler::drw(0x0, 0x1, 0x5),
+                assembler::jp(0x20A),
+            ]
+            .concat(),
+        )
+        .unwrap();
+        emulator.tick_frames(1);
+
+        // Act
+        let framebuffer = emulator.framebuffer();
+
+        // Assert
+        assert!(framebuffer.iter().flatten().any(|pixel| *pixel));
+        for (y, row) in (0..).zip(framebuffer) {
+            for (x, pixel) in (0..).zip(row) {
+                assert_eq!(*pixel, emulator.is_pixel_on(x, y), "{}, {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_framebuffer_size_high_res() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(
+            &[
+                assembler::high(),
+                assembler::ld1(0x0, 0x7F),
+                assembler::ld1(0x1, 0x3F),
+                assembler::ldi(0x050),
+                assembler::drw(0x0, 0x1, 0x1),
+                assembler::jp(0x20A),
+            ]
+            .concat(),
+        )
+        .unwrap();
+        assert_eq!(emulator.framebuffer_size(), (64, 32));
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert!(emulator.is_high_res());
+        assert_eq!(emulator.framebuffer_size(), (128, 64));
+        assert!(emulator.framebuffer()[63][127]);
+        let ascii = emulator.framebuffer_ascii('#', '.');
+        assert_eq!(ascii.lines().count(), 64);
+        assert!(ascii.lines().all(|line| line.len() == 128));
+    }
+
+    #[test]
+    fn test_save_state_round_trip() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(
+            &[
+                assembler::ldi(0x300),
+                assembler::add1(0x0, 0x1),
+                assembler::lddtv(0x0),
+                assembler::ldb(0x0),
+                assembler::call(0x20C),
+                assembler::jp(0x202),
+                assembler::ldi(0x050),
+                assembler::drw(0x0, 0x1, 0x5),
+                assembler::ldi(0x300),
+                assembler::ret(),
+            ]
+            .concat(),
+        )
+        .unwrap();
+        emulator.tick_frames(1);
+        let saved = emulator.save_state();
+        let framebuffer = *emulator.framebuffer();
+        let memory = emulator.memory_slice(0x300, 3).to_vec();
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_ne!(emulator.save_state(), saved);
+        assert_ne!(*emulator.framebuffer(), framebuffer);
+
+        // Act
+        emulator.load_state(&saved);
+
+        // Assert
+        assert_eq!(emulator.save_state(), saved);
+        assert_eq!(*emulator.framebuffer(), framebuffer);
+        assert_eq!(emulator.memory_slice(0x300, 3), memory);
+        assert_eq!(emulator.register.get_v(0x0), saved.register.get_v(0x0));
+        assert_eq!(emulator.delay_timer.value(), saved.delay_timer.value());
+        assert!(emulator.is_running());
+    }
+
+    #[test]
+    fn test_load_state_v_registers() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        let v = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD,
+            0xEE, 0xFF,
+        ];
+        emulator.register.set_v_registers(v);
+        let saved = emulator.save_state();
+        emulator.register.set_v_registers([0x00; 16]);
+
+        // Act
+        emulator.load_state(&saved);
+
+        // Assert
+        assert_eq!(saved.register_v, v);
+        assert_eq!(emulator.register.v_registers(), v);
+    }
+
+    #[test]
+    fn test_step_back() {
+        // Arrange
+        let mut emulator = Emulator::builder().history_depth(1000).build();
+        emulator
+            .load_rom(
+                &[
+                    assembler::ld1(0x0, 0x11),
+                    assembler::ld1(0x1, 0x22),
+                    assembler::add1(0x0, 0x33),
+                ]
+                .concat(),
+            )
+            .unwrap();
+        emulator.set_paused(true);
+
+        // Act
+        for _ in 0..3 {
+            emulator.step_execute();
+        }
+
+        // Assert
+        assert_eq!(emulator.register.get_program_counter(), 0x206);
+        assert_eq!(emulator.register.get_v(0x0), 0x44);
+
+        // Act
+        assert!(emulator.step_back());
+
+        // Assert
+        assert_eq!(emulator.register.get_program_counter(), 0x204);
+        assert_eq!(emulator.register.get_v(0x0), 0x11);
+        assert_eq!(emulator.register.get_v(0x1), 0x22);
+
+        // Act
+        assert!(emulator.step_back());
+
+        // Assert
+        assert_eq!(emulator.register.get_program_counter(), 0x202);
+        assert_eq!(emulator.register.get_v(0x0), 0x11);
+        assert_eq!(emulator.register.get_v(0x1), 0x00);
+    }
+
+    #[test]
+    fn test_step_back_depth() {
+        // Arrange
+        let mut emulator = Emulator::builder().history_depth(2).build();
+        emulator
+            .load_rom(&[assembler::add1(0x0, 0x1), assembler::jp(0x200)].concat())
+            .unwrap();
+
+        // Act
+        for _ in 0..6 {
+            emulator.step_execute();
+        }
+
+        // Assert
+        assert!(emulator.step_back());
+        assert!(emulator.step_back());
+        assert!(!emulator.step_back(), "only the last 2 steps are kept");
+        assert_eq!(emulator.register.get_program_counter(), 0x200);
+        assert_eq!(emulator.register.get_v(0x0), 0x2);
+    }
+
+    #[test]
+    fn test_step_back_without_history() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(&assembler::ld1(0x0, 0x11)).unwrap();
+
+        // Act
+        emulator.step_execute();
+
+        // Assert
+        assert!(!emulator.step_back());
+        assert_eq!(emulator.register.get_v(0x0), 0x11);
+    }
+
+    #[test]
+    fn test_take_display_dirty() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(
+            &[
+                assembler::ldi(0x050),
+                assembler::drw(0x0, 0x0, 0x5),
+                assembler::jp(0x204),
+            ]
+            .concat(),
+        )
+        .unwrap();
+        emulator.take_display_dirty();
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert!(emulator.take_display_dirty());
+        assert!(!emulator.take_display_dirty());
+
+        // Act
+        emulator.tick_frames(1);
+
+        // Assert
+        assert!(!emulator.take_display_dirty(), "only jumped");
+    }
+
+    #[test]
+    fn test_framebuffer_ascii() {
+        // Arrange
+        let emulator = ascii_display();
+
+        // Act
+        let ascii = emulator.framebuffer_ascii('#', '.');
+
+        // Assert
+        let lines = ascii.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 32);
+        assert!(lines.iter().all(|line| line.chars().count() == 64));
+        assert!(lines[0].starts_with("#.#."));
+        assert!(lines[1].starts_with("##.."));
+        assert!(lines[2].starts_with(".#.."));
+        assert_eq!(lines[3], ".".repeat(64));
+        assert!(ascii.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_framebuffer_half_blocks() {
+        // Arrange
+        let emulator = ascii_display();
+
+        // Act
+        let half_blocks = emulator.framebuffer_half_blocks();
+
+        // Assert
+        let lines = half_blocks.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 16);
+        assert!(lines.iter().all(|line| line.chars().count() == 64));
+        assert!(lines[0].starts_with("█▄▀ "));
+        assert!(lines[1].starts_with(" ▀  "));
+        assert_eq!(lines[2], " ".repeat(64));
+    }
+
+    struct MockInput {
+        down: u16,
+        events: Vec<KeyEvent>,
+    }
+
+    impl InputSource for MockInput {
+        fn is_key_down(&self, key: u8) -> bool {
+            self.down & (1 << key) != 0
+        }
+
+        fn poll_event(&mut self) -> Option<KeyEvent> {
+            if self.events.is_empty() {
+                None
+            } else {
+                Some(self.events.remove(0))
+            }
+        }
+    }
+
+    fn skp_rom() -> Vec<u8> {
+        [
+            assembler::ld1(0x0, 0x5),
+            assembler::skp(0x0),
+            assembler::jp(0x204),
+            assembler::jp(0x206),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_poll_input_skp_up() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(&skp_rom()).unwrap();
+        let mut input = MockInput {
+            down: 0b0000_0000_0000_0000,
+            events: Vec::new(),
+        };
+
+        // Act
+        emulator.poll_input(&mut input);
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Halted);
+        assert_eq!(emulator.register.get_program_counter(), 0x204);
+    }
+
+    #[test]
+    fn test_poll_input_skp_down() {
+        // Arrange
+        let mut emulator = Emulator::with_rom(&skp_rom()).unwrap();
+        let mut input = MockInput {
+            down: 0b0000_0000_0010_0000,
+            events: Vec::new(),
+        };
+
+        // Act
+        emulator.poll_input(&mut input);
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Halted);
+        assert_eq!(emulator.register.get_program_counter(), 0x206);
+    }
+
+    #[test]
+    fn test_poll_input_ldk() {
+        // Arrange
+        let rom = [assembler::ldk(0x1), assembler::jp(0x202)].concat();
+        let mut emulator = Emulator::with_rom(&rom).unwrap();
+
+        // Act
+        emulator.poll_input(&mut MockInput {
+            down: 0b0000_0000_0000_0000,
+            events: Vec::new(),
+        });
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Running);
+        assert_eq!(emulator.register.get_program_counter(), 0x200);
+
+        // Act
+        emulator.poll_input(&mut MockInput {
+            down: 0b0000_1000_0000_0000,
+            events: vec![KeyEvent::Pressed(Key::B)],
+        });
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Running);
+        assert_eq!(emulator.register.get_program_counter(), 0x200);
+
+        // Act
+        emulator.poll_input(&mut MockInput {
+            down: 0b0000_0000_0000_0000,
+            events: vec![KeyEvent::Released(Key::B)],
+        });
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Halted);
+        assert_eq!(emulator.register.get_v(0x1), 0xB);
+    }
+
+    #[test]
+    fn test_key_event() {
+        // Arrange
+        let rom = [assembler::ldk(0x1), assembler::jp(0x202)].concat();
+        let mut emulator = Emulator::with_rom(&rom).unwrap();
+
+        // Act
+        emulator.key_event(Key::Key2, true);
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Running);
+        assert!(emulator.keypad.is_pressed(0x2));
+
+        // Act
+        emulator.key_event(Key::Key2, false);
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.status(), EmulatorStatus::Halted);
+        assert_eq!(emulator.register.get_v(0x1), 0x2);
+        assert!(!emulator.keypad.is_pressed(0x2));
+    }
+
+    #[test]
+    fn test_key_wait_held_key() {
+        // Arrange
+        let rom = [
+            assembler::ldk(0x1),
+            assembler::ldk(0x2),
+            assembler::jp(0x204),
+        ]
+        .concat();
+        let mut emulator = Emulator::with_rom(&rom).unwrap();
+
+        // Act
+        emulator.key_event(Key::Key2, true);
+        emulator.tick_frames(1);
+        emulator.key_event(Key::Key2, false);
+        emulator.tick_frames(1);
+
+        // Assert
+        assert_eq!(emulator.register.get_v(0x1), 0x2);
+        assert_eq!(emulator.register.get_program_counter(), 0x202);
+
+        // Act
+        emulator.key_event(Key::Key7, true);
+        emulator.tick_frames(10);
+
+        // Assert
+        assert_eq!(emulator.register.get_v(0x2), 0x0);
+        assert_eq!(emulator.register.get_program_counter(), 0x202);
+    }
+
+    #[test]
+    fn test_set_keys() {
+        // Arrange
+        let mut emulator = Emulator::new();
+
+        // Act
+        emulator.set_keys(0b1000_0000_0000_0101);
+
+        // Assert
+        for key in 0x0..=0xF {
+            assert_eq!(
+                emulator.keypad.is_pressed(key),
+                [0x0, 0x2, 0xF].contains(&key),
+                "key {:X}",
+                key
+            );
+        }
+        assert_eq!(emulator.keypad.read(), Some(0xF));
+
+        // Act
+        emulator.set_keys(0b0000_0000_0000_0100);
+
+        // Assert
+        for key in 0x0..=0xF {
+            assert_eq!(emulator.keypad.is_pressed(key), key == 0x2, "key {:X}", key);
+        }
+    }
+
+    #[cfg(feature = "editor")]
+    #[test]
+    fn test_set_ram_byte_take_dirty_memory() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator.take_dirty_memory();
+
+        // Act
+        emulator.set_ram_byte(0x302, 0x42);
+
+        // Assert
+        let dirty = emulator.take_dirty_memory();
+        assert_eq!(dirty.len(), 1);
+        assert_eq!(dirty[0].0, 0x300);
+        assert_eq!(dirty[0].1[0x2], 0x42);
+    }
+
+    #[cfg(feature = "screenshot")]
+    #[test]
+    fn test_screenshot_high_res() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator.display.set_high_res(true);
+        emulator.display.set_pixel(127, 63, true);
+
+        // Act
+        let png = emulator.screenshot(1, [0xFF; 3], [0x00; 3]).unwrap();
+
+        // Assert
+        let image = image::load_from_memory(&png).unwrap().into_rgb8();
+        assert_eq!(image.dimensions(), (128, 64));
+        assert_eq!(image.get_pixel(127, 63), &image::Rgb([0xFF; 3]));
+        assert_eq!(image.get_pixel(0, 0), &image::Rgb([0x00; 3]));
+    }
+
+    #[cfg(feature = "recording")]
+    #[test]
+    fn test_recording_blinking_pixel() {
+        // Arrange
+        let mut emulator = Emulator::builder().max_draws_per_frame(Some(1)).build();
+        emulator
+            .load_rom(
+                &[
+                    assembler::ldi(0x050),
+                    assembler::drw(0x0, 0x0, 0x1),
+                    assembler::jp(0x202),
+                ]
+                .concat(),
+            )
+            .unwrap();
+
+        // Act
+        emulator.start_recording();
+        emulator.tick_frames(4);
+        let gif = emulator.stop_recording().unwrap();
+
+        // Assert
+        assert!(!emulator.is_recording());
+        let mut decoder = gif::DecodeOptions::new().read_info(gif.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (64, 32));
+        let mut frames = 0;
+        while decoder.read_next_frame().unwrap().is_some() {
+            frames += 1;
+        }
+        assert_eq!(frames, 4);
+    }
+
+    #[test]
+    fn test_current_sprite() {
+        // Arrange
+        let mut emulator = Emulator::new();
+        emulator.register.set_i(0x050);
+
+        // Act
+        let sprite = emulator.current_sprite(5);
+
+        // Assert
+        assert_eq!(sprite, vec![0xF0, 0x90, 0x90, 0x90, 0xF0]);
+    }
+
+    #[test]
+    fn test_current_sprite_wraps() {
+        // Arrange
+        let mut wrapping = Emulator::new();
+        let mut clamping = Emulator::builder()
+            .quirks(Quirks {
+                wrap_sprite_reads: false,
+                ..Quirks::default()
+            })
+            .build();
+        for emulator in [&mut wrapping, &mut clamping] {
+            emulator.memory.set_byte(0xFFF, 0xAA);
+            emulator.register.set_i(0xFFF);
+        }
+
+        // Act
+        let wrapped = wrapping.current_sprite(3);
+        let clamped = clamping.current_sprite(3);
+
+        // Assert
+        assert_eq!(wrapped, vec![0xAA, 0x00, 0x00]);
+        assert_eq!(clamped, vec![0xAA, 0xAA, 0xAA]);
+    }
 }
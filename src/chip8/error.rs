@@ -0,0 +1,20 @@
+/// Reason a ROM could not be loaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadError {
+    /// ROM is longer than the memory between 0x200 and the end of RAM
+    RomTooLarge { len: usize, max: usize },
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::RomTooLarge { len, max } => write!(
+                f,
+                "rom is {} bytes but at most {} bytes fit in memory",
+                len, max
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
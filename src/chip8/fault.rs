@@ -0,0 +1,46 @@
+/// Reason the emulator stopped executing a program
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Fault {
+    /// Instruction at the address is not supported by the current display mode or quirks
+    IllegalInstruction { address: u16 },
+    /// 0nnn at the address calls a machine code routine, which only the original hardware could run,
+    /// or the program ran into zeroed memory
+    MachineCodeRoutine { address: u16 },
+    /// Instruction at the address calls itself, which would recurse until the stack overflows
+    SelfRecursiveCall { address: u16 },
+    /// CALL at the address was made with every level of the stack already in use
+    StackOverflow { address: u16 },
+    /// Word at the address does not decode to any instruction, such as data the program jumped into
+    UnknownInstruction { address: u16 },
+}
+
+impl std::fmt::Display for Fault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Fault::IllegalInstruction { address } => write!(
+                f,
+                "instruction at {:03X} is not supported by the current display mode or quirks",
+                address
+            ),
+            Fault::MachineCodeRoutine { address } => {
+                write!(
+                    f,
+                    "instruction at {:03X} calls a machine code routine",
+                    address
+                )
+            }
+            Fault::SelfRecursiveCall { address } => {
+                write!(f, "instruction at {:03X} calls itself", address)
+            }
+            Fault::StackOverflow { address } => {
+                write!(f, "instruction at {:03X} overflows the stack", address)
+            }
+            Fault::UnknownInstruction { address } => {
+                write!(f, "word at {:03X} is not an instruction", address)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Fault {}
@@ -0,0 +1,138 @@
+use super::{emulator::Emulator, keypad::Key};
+
+/// CHIP-8 interpreter for embedding without Bevy, such as in test runners
+///
+/// Wraps an [`Emulator`] with its default configuration, reach for the emulator
+/// itself when quirks, timing or diagnostics need changing.
+pub struct Chip8 {
+    emulator: Emulator,
+}
+
+impl Chip8 {
+    #[must_use]
+    pub fn new() -> Chip8 {
+        Chip8 {
+            emulator: Emulator::builder().build(),
+        }
+    }
+
+    /// Loads the ROM at 0x200 and starts running it
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the ROM does not fit in memory.
+    pub fn load_rom(&mut self, rom: &[u8]) -> crate::Result<()> {
+        self.emulator.load_rom(rom)
+    }
+
+    /// Executes the instruction at the program counter without ticking the timers
+    pub fn step(&mut self) {
+        self.emulator.step_execute();
+    }
+
+    /// Emulates the time passing, ticking the timers and executing instructions at 700 Hz
+    pub fn tick(&mut self, delta: std::time::Duration) {
+        self.emulator.emulate(&delta);
+    }
+
+    /// Pixels as rows from the top, `true` when on, 64 x 32 unless the program switched to 128 x 64
+    #[must_use]
+    pub fn framebuffer(&self) -> &[[bool; 128]; 64] {
+        self.emulator.framebuffer()
+    }
+
+    pub fn press(&mut self, key: Key) {
+        self.emulator.key_pressed(key);
+    }
+
+    pub fn release(&mut self, key: Key) {
+        self.emulator.key_released(key);
+    }
+
+    #[must_use]
+    pub fn emulator(&self) -> &Emulator {
+        &self.emulator
+    }
+
+    pub fn emulator_mut(&mut self) -> &mut Emulator {
+        &mut self.emulator
+    }
+}
+
+impl Default for Chip8 {
+    fn default() -> Chip8 {
+        Chip8::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler, *};
+
+    #[test]
+    fn test_tick() {
+        // Arrange
+        let rom = std::fs::read("./roms/IBM Logo.ch8").unwrap();
+        let mut chip8 = Chip8::new();
+        chip8.load_rom(&rom).unwrap();
+
+        // Act
+        chip8.tick(std::time::Duration::from_secs(1));
+
+        // Assert
+        let framebuffer = chip8.framebuffer();
+        assert!(framebuffer[8][12], "top left corner of the \"I\"");
+        assert!(!framebuffer[0][0]);
+    }
+
+    #[test]
+    fn test_step() {
+        // Arrange
+        let mut chip8 = Chip8::new();
+        chip8
+            .load_rom(
+                &[
+                    assembler::ldi(0x050),
+                    assembler::drw(0x0, 0x0, 0x5),
+                    assembler::jp(0x204),
+                ]
+                .concat(),
+            )
+            .unwrap();
+
+        // Act
+        chip8.step();
+
+        // Assert
+        assert!(!chip8.framebuffer()[0][0]);
+
+        // Act
+        chip8.step();
+
+        // Assert
+        assert!(chip8.framebuffer()[0][0]);
+        assert_eq!(chip8.emulator().cycles(), 2);
+    }
+
+    #[test]
+    fn test_press_release() {
+        // Arrange
+        let mut chip8 = Chip8::new();
+        chip8
+            .load_rom(&[assembler::ldk(0x0), assembler::exit()].concat())
+            .unwrap();
+
+        // Act
+        chip8.press(Key::Key5);
+        chip8.step();
+        chip8.release(Key::Key5);
+        chip8.step();
+        chip8.step();
+
+        // Assert
+        assert_eq!(
+            chip8.emulator().status(),
+            super::super::emulator::EmulatorStatus::Exited
+        );
+    }
+}
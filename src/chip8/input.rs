@@ -0,0 +1,17 @@
+use super::keypad::Key;
+
+/// Change to a key's state reported by an [`InputSource`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyEvent {
+    Pressed(Key),
+    Released(Key),
+}
+
+/// Supplies key presses to an emulator, so frontends can read input from any backend
+pub trait InputSource {
+    /// Whether the key, `0x0` through `0xF`, is currently held down
+    fn is_key_down(&self, key: u8) -> bool;
+
+    /// Next key press or release since the last poll, `None` once there are no more
+    fn poll_event(&mut self) -> Option<KeyEvent>;
+}
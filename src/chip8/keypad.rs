@@ -1,5 +1,7 @@
+use super::input::{InputSource, KeyEvent};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum Key {
+pub enum Key {
     Key0,
     Key1,
     Key2,
@@ -19,25 +21,75 @@ pub(crate) enum Key {
     F,
 }
 
+impl From<Key> for u8 {
+    /// Hex digit the key is labelled with
+    fn from(key: Key) -> u8 {
+        Keypad::map(key)
+    }
+}
+
 pub(super) struct Keypad {
+    /// Releases keys which weren't pressed again within the duration, for frontends without key up events
+    auto_release: Option<std::time::Duration>,
+    /// Time since each key was last pressed
+    held: [std::time::Duration; 0x10],
     last_key: Option<Key>,
     pressed: [bool; 0x10],
+    /// Accessibility mode, a press latches a key until it is pressed again and releases are ignored
+    sticky: bool,
 }
 
 impl Keypad {
+    const KEYS: [Key; 0x10] = [
+        Key::Key0,
+        Key::Key1,
+        Key::Key2,
+        Key::Key3,
+        Key::Key4,
+        Key::Key5,
+        Key::Key6,
+        Key::Key7,
+        Key::Key8,
+        Key::Key9,
+        Key::A,
+        Key::B,
+        Key::C,
+        Key::D,
+        Key::E,
+        Key::F,
+    ];
+
     pub(super) fn new() -> Keypad {
         Keypad {
+            auto_release: None,
+            held: [std::time::Duration::ZERO; 0x10],
             last_key: None,
             pressed: [false; 0x10],
+            sticky: false,
         }
     }
 
     pub(crate) fn pressed(&mut self, key: Key) {
+        if self.sticky && self.pressed[Self::map(key) as usize] {
+            self.release(key);
+        } else {
+            self.press(key);
+        }
+    }
+
+    pub(crate) fn released(&mut self, key: Key) {
+        if !self.sticky {
+            self.release(key);
+        }
+    }
+
+    fn press(&mut self, key: Key) {
         self.last_key = Some(key);
         self.pressed[Self::map(key) as usize] = true;
+        self.held[Self::map(key) as usize] = std::time::Duration::ZERO;
     }
 
-    pub(crate) fn released(&mut self, key: Key) {
+    fn release(&mut self, key: Key) {
         if let Some(current_key) = self.last_key {
             if current_key == key {
                 self.last_key = None;
@@ -46,12 +98,86 @@ impl Keypad {
         self.pressed[Self::map(key) as usize] = false;
     }
 
+    /// Presses every key whose bit is set in the mask, bit `n` being key `n`, and releases the rest
+    pub(super) fn set_keys(&mut self, mask: u16) {
+        for (n, key) in Self::KEYS.into_iter().enumerate() {
+            let down = mask & (1 << n) != 0;
+
+            if down && !self.pressed[n] {
+                self.press(key);
+            } else if !down && self.pressed[n] {
+                self.release(key);
+            }
+        }
+    }
+
+    /// Applies every pending event from the source, then presses and releases keys to match what it holds down
+    pub(super) fn poll(&mut self, input: &mut impl InputSource) {
+        while let Some(event) = input.poll_event() {
+            match event {
+                KeyEvent::Pressed(key) => self.pressed(key),
+                KeyEvent::Released(key) => self.released(key),
+            }
+        }
+
+        // latched keys are only changed by presses
+        if self.sticky {
+            return;
+        }
+
+        for (n, key) in Self::KEYS.into_iter().enumerate() {
+            let down = input.is_key_down(Self::map(key));
+
+            if down && !self.pressed[n] {
+                self.press(key);
+            } else if !down && self.pressed[n] {
+                self.release(key);
+            }
+        }
+    }
+
+    pub(super) fn set_auto_release(&mut self, auto_release: Option<std::time::Duration>) {
+        self.auto_release = auto_release;
+    }
+
+    pub(super) fn set_sticky(&mut self, sticky: bool) {
+        self.sticky = sticky;
+    }
+
+    pub(super) fn tick(&mut self, delta: &std::time::Duration) {
+        if let Some(auto_release) = self.auto_release {
+            for (n, key) in Self::KEYS.into_iter().enumerate() {
+                if self.pressed[n] {
+                    self.held[n] = self.held[n].saturating_add(*delta);
+
+                    if self.held[n] >= auto_release {
+                        self.release(key);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Most recently pressed key while it is held, otherwise the lowest key still held
     pub(super) fn read(&self) -> Option<u8> {
-        self.last_key.map(Self::map)
+        self.last_key
+            .map(Self::map)
+            .or_else(|| self.pressed_keys().next())
+    }
+
+    /// Keys held down, lowest first
+    pub(super) fn pressed_keys(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..0x10).filter(|&key| self.pressed[usize::from(key)])
+    }
+
+    /// Key on the hex keypad, `None` above 0xF
+    pub(super) fn key(index: u8) -> Option<Key> {
+        Self::KEYS.get(usize::from(index)).copied()
     }
 
+    /// `false` above 0xF, where there is no key to hold down
     pub(super) fn is_pressed(&self, key: u8) -> bool {
-        self.pressed[key as usize]
+        self.pressed.get(usize::from(key)).copied().unwrap_or(false)
     }
 
     fn map(key: Key) -> u8 {
@@ -75,3 +201,126 @@ impl Keypad {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_auto_release() {
+        // Arrange
+        let mut keypad = Keypad::new();
+        keypad.set_auto_release(Some(std::time::Duration::from_millis(100)));
+        keypad.pressed(Key::A);
+
+        // Act
+        keypad.tick(&std::time::Duration::from_millis(60));
+
+        // Assert
+        assert!(keypad.is_pressed(0xA));
+        assert_eq!(keypad.read(), Some(0xA));
+
+        // Act
+        keypad.tick(&std::time::Duration::from_millis(60));
+
+        // Assert
+        assert!(!keypad.is_pressed(0xA));
+        assert_eq!(keypad.read(), None);
+    }
+
+    #[test]
+    fn test_tick_auto_release_refreshed() {
+        // Arrange
+        let mut keypad = Keypad::new();
+        keypad.set_auto_release(Some(std::time::Duration::from_millis(100)));
+        keypad.pressed(Key::A);
+
+        // Act
+        keypad.tick(&std::time::Duration::from_millis(60));
+        keypad.pressed(Key::A);
+        keypad.tick(&std::time::Duration::from_millis(60));
+
+        // Assert
+        assert!(keypad.is_pressed(0xA));
+    }
+
+    #[test]
+    fn test_sticky() {
+        // Arrange
+        let mut keypad = Keypad::new();
+        keypad.set_sticky(true);
+
+        // Act
+        keypad.pressed(Key::A);
+        keypad.released(Key::A);
+
+        // Assert
+        assert!(keypad.is_pressed(0xA));
+        assert_eq!(keypad.read(), Some(0xA));
+
+        // Act
+        keypad.pressed(Key::A);
+        keypad.released(Key::A);
+
+        // Assert
+        assert!(!keypad.is_pressed(0xA));
+        assert_eq!(keypad.read(), None);
+    }
+
+    #[test]
+    fn test_not_sticky() {
+        // Arrange
+        let mut keypad = Keypad::new();
+
+        // Act
+        keypad.pressed(Key::A);
+        keypad.pressed(Key::A);
+
+        // Assert
+        assert!(keypad.is_pressed(0xA));
+
+        // Act
+        keypad.released(Key::A);
+
+        // Assert
+        assert!(!keypad.is_pressed(0xA));
+    }
+
+    #[test]
+    fn test_read_held_key() {
+        // Arrange
+        let mut keypad = Keypad::new();
+        keypad.pressed(Key::Key1);
+        keypad.pressed(Key::Key2);
+
+        // Assert
+        assert_eq!(keypad.read(), Some(0x2));
+        assert_eq!(keypad.pressed_keys().collect::<Vec<_>>(), vec![0x1, 0x2]);
+
+        // Act
+        keypad.released(Key::Key2);
+
+        // Assert
+        assert_eq!(keypad.read(), Some(0x1), "1 is still held");
+        assert_eq!(keypad.pressed_keys().collect::<Vec<_>>(), vec![0x1]);
+
+        // Act
+        keypad.released(Key::Key1);
+
+        // Assert
+        assert_eq!(keypad.read(), None);
+    }
+
+    #[test]
+    fn test_tick_without_auto_release() {
+        // Arrange
+        let mut keypad = Keypad::new();
+        keypad.pressed(Key::A);
+
+        // Act
+        keypad.tick(&std::time::Duration::from_secs(10));
+
+        // Assert
+        assert!(keypad.is_pressed(0xA));
+    }
+}
@@ -1,39 +1,328 @@
 use std::io::{Cursor, Write};
 
+use super::{error::LoadError, font::Font, watch::WatchKind};
+
 pub(super) struct Memory {
+    /// Rows of 16 bytes written since they were last taken, so the editor only refreshes what changed
+    #[cfg(feature = "editor")]
+    dirty_rows: Vec<bool>,
     /// Memory: CHIP-8 has direct access to up to 4 kilobytes of RAM
     ram: Vec<u8>,
+    /// Address after the last byte of the loaded ROM
+    rom_end: u16,
+    /// First watched address accessed since it was last taken
+    watch_hit: Option<(u16, WatchKind)>,
+    watchpoints: std::collections::BTreeMap<u16, WatchKind>,
+    /// Lowest and highest address written by instructions since they were last taken
+    writes: Option<(u16, u16)>,
 }
 
 impl Memory {
     pub(super) fn new() -> Memory {
-        Memory { ram: vec![0; 4096] }
+        Memory {
+            #[cfg(feature = "editor")]
+            dirty_rows: vec![true; 4096 / 16],
+            ram: vec![0; 4096],
+            rom_end: 0x200,
+            watch_hit: None,
+            watchpoints: std::collections::BTreeMap::new(),
+            writes: None,
+        }
     }
 
-    pub(super) fn load_font(&mut self, font: &[u8]) -> crate::Result<()> {
-        let mut cursor = Cursor::new(&mut self.ram);
+    /// Loads the small font at 0x050 and the SUPER-CHIP big font right after it at 0x0A0
+    pub(super) fn load_font(&mut self, font: &Font) -> crate::Result<()> {
+        let mut cursor = Cursor::new(self.ram.as_mut_slice());
         cursor.set_position(0x050);
-        cursor.write_all(font)?;
+        cursor.write_all(font.data())?;
+        cursor.write_all(font.big_data())?;
+        #[cfg(feature = "editor")]
+        self.mark_dirty(0x050, font.data().len() + font.big_data().len());
         Ok(())
     }
 
+    pub(super) fn ram(&self) -> &[u8] {
+        &self.ram
+    }
+
+    /// Replaces the whole of memory, as saved together with the end of the ROM it holds
+    pub(super) fn load_ram(&mut self, ram: &[u8], rom_end: u16) {
+        self.ram.copy_from_slice(ram);
+        self.rom_end = rom_end;
+        #[cfg(feature = "editor")]
+        self.mark_dirty(0, self.ram.len());
+    }
+
     pub(super) fn load_rom(&mut self, rom: &[u8]) -> crate::Result<()> {
-        let mut cursor = Cursor::new(&mut self.ram);
+        let max = self.ram.len() - 0x200;
+        if rom.len() > max {
+            return Err(LoadError::RomTooLarge {
+                len: rom.len(),
+                max,
+            }
+            .into());
+        }
+
+        let mut cursor = Cursor::new(self.ram.as_mut_slice());
         cursor.set_position(0x200);
         cursor.write_all(rom)?;
+        self.rom_end = 0x200 + u16::try_from(rom.len())?;
+        #[cfg(feature = "editor")]
+        self.mark_dirty(0x200, rom.len());
         Ok(())
     }
 
+    /// Addresses wrap at 4 kilobytes, as they do on the COSMAC VIP, so a runaway program can't panic
     pub(super) fn get_byte(&self, address: u16) -> u8 {
-        self.ram[address as usize]
+        self.ram[usize::from(address & 0x0FFF)]
+    }
+
+    /// Byte an instruction reads as data, tripping a watchpoint on the address
+    pub(super) fn read_byte(&mut self, address: u16) -> u8 {
+        self.watch_access(address & 0x0FFF, WatchKind::Read);
+        self.get_byte(address)
     }
 
+    /// Records an instruction reading the address without returning the byte,
+    /// for reads that go through [`Memory::get_byte`]
+    pub(super) fn note_read(&mut self, address: u16) {
+        self.watch_access(address & 0x0FFF, WatchKind::Read);
+    }
+
+    /// Addresses wrap at 4 kilobytes, as they do on the COSMAC VIP, so a runaway program can't panic
     pub(super) fn set_byte(&mut self, address: u16, byte: u8) {
-        self.ram[address as usize] = byte;
+        let address = address & 0x0FFF;
+        self.watch_access(address, WatchKind::Write);
+        self.ram[usize::from(address)] = byte;
+        #[cfg(feature = "editor")]
+        self.mark_dirty(address as usize, 1);
+
+        self.writes = Some(match self.writes {
+            Some((low, high)) => (low.min(address), high.max(address)),
+            None => (address, address),
+        });
+    }
+
+    /// Writes a byte from outside the program, neither tripping watchpoints nor counting as the
+    /// running program writing to memory
+    pub(super) fn poke(&mut self, address: u16, byte: u8) {
+        let address = address & 0x0FFF;
+        self.ram[usize::from(address)] = byte;
+        #[cfg(feature = "editor")]
+        self.mark_dirty(address as usize, 1);
+    }
+
+    /// Lowest and highest address written since the last call
+    pub(super) fn take_writes(&mut self) -> Option<(u16, u16)> {
+        self.writes.take()
+    }
+
+    /// Watched address and the access to it since the last call, the first one when there were several
+    pub(super) fn take_watch_hit(&mut self) -> Option<(u16, WatchKind)> {
+        self.watch_hit.take()
     }
 
+    pub(super) fn watch(&mut self, address: u16, kind: WatchKind) {
+        self.watchpoints.insert(address & 0x0FFF, kind);
+    }
+
+    pub(super) fn unwatch(&mut self, address: u16) -> bool {
+        self.watchpoints.remove(&(address & 0x0FFF)).is_some()
+    }
+
+    pub(super) fn watchpoints(&self) -> &std::collections::BTreeMap<u16, WatchKind> {
+        &self.watchpoints
+    }
+
+    pub(super) fn clear_watchpoints(&mut self) {
+        self.watchpoints.clear();
+    }
+
+    /// Replaces the watchpoints, so they outlive memory being power cycled
+    pub(super) fn set_watchpoints(
+        &mut self,
+        watchpoints: std::collections::BTreeMap<u16, WatchKind>,
+    ) {
+        self.watchpoints = watchpoints;
+    }
+
+    fn watch_access(&mut self, address: u16, access: WatchKind) {
+        if self.watch_hit.is_none()
+            && matches!(self.watchpoints.get(&address), Some(kind) if kind.matches(access))
+        {
+            self.watch_hit = Some((address, access));
+        }
+    }
+
+    pub(super) fn rom_end(&self) -> u16 {
+        self.rom_end
+    }
+
+    /// Bytes from `start`, truncated at the end of memory
+    pub(super) fn get_slice(&self, start: u16, len: u16) -> &[u8] {
+        let start = usize::from(start).min(self.ram.len());
+        let end = (start + usize::from(len)).min(self.ram.len());
+        &self.ram[start..end]
+    }
+
+    /// Indexes of the rows of 16 bytes written since the last call
     #[cfg(feature = "editor")]
-    pub(super) fn get_ram(&self) -> &[u8] {
-        &self.ram
+    pub(super) fn take_dirty_rows(&mut self) -> Vec<usize> {
+        let rows = self
+            .dirty_rows
+            .iter()
+            .enumerate()
+            .filter(|(_, dirty)| **dirty)
+            .map(|(row, _)| row)
+            .collect();
+        self.dirty_rows.fill(false);
+        rows
+    }
+
+    #[cfg(feature = "editor")]
+    fn mark_dirty(&mut self, address: usize, len: usize) {
+        for address in address..address + len {
+            self.dirty_rows[address / 16] = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_byte_wraps() {
+        // Arrange
+        let mut memory = Memory::new();
+        memory.set_byte(0x000, 0x12);
+        memory.set_byte(0xFFF, 0x34);
+
+        // Act & Assert
+        assert_eq!(memory.get_byte(0x1000), 0x12);
+        assert_eq!(memory.get_byte(0xFFFF), 0x34);
+    }
+
+    #[test]
+    fn test_set_byte_wraps() {
+        // Arrange
+        let mut memory = Memory::new();
+
+        // Act
+        memory.set_byte(0x1000, 0x12);
+        memory.set_byte(0xFFFF, 0x34);
+
+        // Assert
+        assert_eq!(memory.get_byte(0x000), 0x12);
+        assert_eq!(memory.get_byte(0xFFF), 0x34);
+        assert_eq!(memory.take_writes(), Some((0x000, 0xFFF)));
+    }
+
+    #[test]
+    fn test_watch_hit() {
+        // Arrange
+        let mut memory = Memory::new();
+        memory.watch(0x300, WatchKind::Write);
+        memory.watch(0x301, WatchKind::Read);
+
+        // Act
+        memory.read_byte(0x300);
+        memory.set_byte(0x301, 0xFF);
+
+        // Assert
+        assert_eq!(memory.take_watch_hit(), None);
+
+        // Act
+        memory.read_byte(0x1301);
+        memory.set_byte(0x300, 0xFF);
+
+        // Assert
+        assert_eq!(memory.take_watch_hit(), Some((0x301, WatchKind::Read)));
+        assert_eq!(memory.take_watch_hit(), None);
+    }
+
+    #[test]
+    fn test_load_font() {
+        // Arrange
+        let mut memory = Memory::new();
+        let font = Font::new();
+
+        // Act
+        memory.load_font(&font).unwrap();
+
+        // Assert
+        assert_eq!(memory.get_slice(0x050, 5), &font.data()[..5], "small 0");
+        assert_eq!(memory.get_slice(0x0A0, 10), &font.big_data()[..10], "big 0");
+        assert_eq!(
+            memory.get_slice(0x136, 10),
+            &font.big_data()[0x96..],
+            "big F"
+        );
+        assert_eq!(memory.get_byte(0x140), 0x00);
+    }
+
+    #[test]
+    fn test_load_rom_too_large() {
+        // Arrange
+        let mut memory = Memory::new();
+
+        // Act
+        let error = memory.load_rom(&[0xFF; 0x1001]).unwrap_err();
+
+        // Assert
+        assert_eq!(
+            error.downcast_ref::<LoadError>(),
+            Some(&LoadError::RomTooLarge {
+                len: 0x1001,
+                max: 0xE00
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "rom is 4097 bytes but at most 3584 bytes fit in memory"
+        );
+        assert_eq!(memory.get_byte(0x200), 0x00);
+    }
+
+    #[test]
+    fn test_load_rom_fills_memory() {
+        // Arrange
+        let mut memory = Memory::new();
+
+        // Act
+        memory.load_rom(&[0xFF; 0xE00]).unwrap();
+
+        // Assert
+        assert_eq!(memory.get_byte(0xFFF), 0xFF);
+        assert_eq!(memory.rom_end(), 0x1000);
+    }
+
+    #[cfg(feature = "editor")]
+    #[test]
+    fn test_take_dirty_rows() {
+        // Arrange
+        let mut memory = Memory::new();
+        memory.take_dirty_rows();
+
+        // Act
+        memory.set_byte(0x213, 0xFF);
+
+        // Assert
+        assert_eq!(memory.take_dirty_rows(), vec![0x21]);
+        assert_eq!(memory.take_dirty_rows(), Vec::<usize>::new());
+    }
+
+    #[cfg(feature = "editor")]
+    #[test]
+    fn test_take_dirty_rows_load_rom() {
+        // Arrange
+        let mut memory = Memory::new();
+        memory.take_dirty_rows();
+
+        // Act
+        memory.load_rom(&[0xFF; 0x11]).unwrap();
+
+        // Assert
+        assert_eq!(memory.take_dirty_rows(), vec![0x20, 0x21]);
     }
 }
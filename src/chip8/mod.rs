@@ -1,12 +1,32 @@
+pub mod asm;
+#[cfg(test)]
+mod assembler;
+pub mod cartridge;
 mod cpu;
+pub mod diagnostics;
+pub mod disassembler;
 mod display;
-pub(crate) mod emulator;
+pub mod emulator;
+pub mod error;
+pub mod fault;
 mod font;
-pub(crate) mod keypad;
+pub mod headless;
+pub mod input;
+pub mod keypad;
 mod memory;
 mod operation;
+pub mod profile;
+pub mod quirks;
+#[cfg(feature = "recording")]
+pub mod recording;
 mod register;
+#[cfg(feature = "screenshot")]
+mod screenshot;
+pub mod state;
 mod timer;
+pub mod warning;
+pub mod wasm;
+pub mod watch;
 
 #[cfg(test)]
 mod tests {
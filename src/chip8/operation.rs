@@ -1,12 +1,21 @@
 #![allow(clippy::doc_markdown)]
 #![allow(clippy::upper_case_acronyms)]
 
-use super::{display::Display, keypad::Keypad, memory::Memory, register::Register, timer::Timer};
+use super::{
+    display::Display, keypad::Keypad, memory::Memory, quirks::Quirks, register::Register,
+    timer::Timer,
+};
 
 #[derive(Debug, PartialEq)]
 pub(super) enum Operation {
+    SCD(SCD),
     CLS(CLS),
     RET(RET),
+    SCR(SCR),
+    SCL(SCL),
+    EXIT(EXIT),
+    LOW(LOW),
+    HIGH(HIGH),
     SYS(SYS),
     JP(JP),
     CALL(CALL),
@@ -37,9 +46,23 @@ pub(super) enum Operation {
     LDST(LDST),
     ADDI(ADDI),
     LDF(LDF),
+    LDHF(LDHF),
     LDB(LDB),
     LDIV(LDIV),
     LDVI(LDVI),
+    LDRV(LDRV),
+    LDVR(LDVR),
+    UNKNOWN(UNKNOWN),
+}
+
+/// 00Cn - SCD nibble
+///
+/// Scroll display n lines down.
+///
+/// SUPER-CHIP only, vacated rows at the top are turned off.
+#[derive(Debug, PartialEq)]
+pub(super) struct SCD {
+    n: u8,
 }
 
 /// 00E0 - CLS
@@ -56,6 +79,46 @@ pub(super) struct CLS;
 #[derive(Debug, PartialEq)]
 pub(super) struct RET;
 
+/// 00FB - SCR
+///
+/// Scroll display 4 pixels right.
+///
+/// SUPER-CHIP only, vacated columns at the left are turned off.
+#[derive(Debug, PartialEq)]
+pub(super) struct SCR;
+
+/// 00FC - SCL
+///
+/// Scroll display 4 pixels left.
+///
+/// SUPER-CHIP only, vacated columns at the right are turned off.
+#[derive(Debug, PartialEq)]
+pub(super) struct SCL;
+
+/// 00FD - EXIT
+///
+/// Exit the interpreter.
+///
+/// SUPER-CHIP only, the program counter is left on the instruction.
+#[derive(Debug, PartialEq)]
+pub(super) struct EXIT;
+
+/// 00FE - LOW
+///
+/// Disable extended screen mode.
+///
+/// SUPER-CHIP only, switches back to the 64 x 32 display and clears it.
+#[derive(Debug, PartialEq)]
+pub(super) struct LOW;
+
+/// 00FF - HIGH
+///
+/// Enable extended screen mode for full-screen graphics.
+///
+/// SUPER-CHIP only, switches to the 128 x 64 display and clears it.
+#[derive(Debug, PartialEq)]
+pub(super) struct HIGH;
+
 /// 0nnn - SYS addr
 ///
 /// Jump to a machine code routine at nnn.
@@ -291,6 +354,7 @@ pub(super) struct RND {
 /// Sprites are XORed onto the existing screen.
 /// If this causes any pixels to be erased, VF is set to 1, otherwise it is set to 0.
 /// If the sprite is positioned so part of it is outside the coordinates of the display, it wraps around to the opposite side of the screen.
+/// SUPER-CHIP: in extended screen mode, Dxy0 draws a 16 x 16 sprite of 32 bytes, two per row.
 /// See instruction 8xy3 for more information on XOR, and section 2.4, Display, for more information on the Chip-8 screen and sprites.
 #[derive(Debug, PartialEq)]
 pub(super) struct DRW {
@@ -379,6 +443,16 @@ pub(super) struct LDF {
     x: u8,
 }
 
+/// Fx30 - LD HF, Vx
+///
+/// Set I = location of 10-byte sprite for digit Vx.
+///
+/// SUPER-CHIP only, the big font's 8 x 10 sprites follow the small font in memory.
+#[derive(Debug, PartialEq)]
+pub(super) struct LDHF {
+    x: u8,
+}
+
 /// Fx33 - LD B, Vx
 ///
 /// Store BCD representation of Vx in memory locations I, I+1, and I+2.
@@ -409,13 +483,45 @@ pub(super) struct LDVI {
     x: u8,
 }
 
+/// Fx75 - LD R, Vx
+///
+/// Store V0..Vx in RPL user flags (x <= 7).
+///
+/// SUPER-CHIP only, the HP-48's flags outlive the program.
+#[derive(Debug, PartialEq)]
+pub(super) struct LDRV {
+    x: u8,
+}
+
+/// Fx85 - LD Vx, R
+///
+/// Read V0..Vx from RPL user flags (x <= 7).
+///
+/// SUPER-CHIP only.
+#[derive(Debug, PartialEq)]
+pub(super) struct LDVR {
+    x: u8,
+}
+
+/// Bytes which don't decode to any supported instruction, such as sprite data.
+#[derive(Debug, PartialEq)]
+pub(super) struct UNKNOWN {
+    opcode: u16,
+}
+
 impl Operation {
     pub(super) fn parse(bytes: [u8; 2]) -> Operation {
         let nibbles = nibble::from_bytes(bytes);
 
         match nibbles {
+            [0x0, 0x0, 0xC, n4] => Operation::SCD(SCD::new(nibble::to_n(n4))),
             [0x0, 0x0, 0xE, 0x0] => Operation::CLS(CLS::new()),
             [0x0, 0x0, 0xE, 0xE] => Operation::RET(RET::new()),
+            [0x0, 0x0, 0xF, 0xB] => Operation::SCR(SCR::new()),
+            [0x0, 0x0, 0xF, 0xC] => Operation::SCL(SCL::new()),
+            [0x0, 0x0, 0xF, 0xD] => Operation::EXIT(EXIT::new()),
+            [0x0, 0x0, 0xF, 0xE] => Operation::LOW(LOW::new()),
+            [0x0, 0x0, 0xF, 0xF] => Operation::HIGH(HIGH::new()),
             [0x0, n2, n3, n4] => Operation::SYS(SYS::new(nibble::to_nnn(n2, n3, n4))),
             [0x1, n2, n3, n4] => Operation::JP(JP::new(nibble::to_nnn(n2, n3, n4))),
             [0x2, n2, n3, n4] => Operation::CALL(CALL::new(nibble::to_nnn(n2, n3, n4))),
@@ -454,14 +560,203 @@ impl Operation {
             [0xF, n2, 0x1, 0x8] => Operation::LDST(LDST::new(nibble::to_n(n2))),
             [0xF, n2, 0x1, 0xE] => Operation::ADDI(ADDI::new(nibble::to_n(n2))),
             [0xF, n2, 0x2, 0x9] => Operation::LDF(LDF::new(nibble::to_n(n2))),
+            [0xF, n2, 0x3, 0x0] => Operation::LDHF(LDHF::new(nibble::to_n(n2))),
             [0xF, n2, 0x3, 0x3] => Operation::LDB(LDB::new(nibble::to_n(n2))),
             [0xF, n2, 0x5, 0x5] => Operation::LDIV(LDIV::new(nibble::to_n(n2))),
             [0xF, n2, 0x6, 0x5] => Operation::LDVI(LDVI::new(nibble::to_n(n2))),
-            [n1, n2, n3, n4] => todo!("{:1X} {:1X} {:1X} {:1X}", n1, n2, n3, n4),
+            [0xF, n2, 0x7, 0x5] => Operation::LDRV(LDRV::new(nibble::to_n(n2))),
+            [0xF, n2, 0x8, 0x5] => Operation::LDVR(LDVR::new(nibble::to_n(n2))),
+            _ => Operation::UNKNOWN(UNKNOWN::new(u16::from_be_bytes(bytes))),
+        }
+    }
+
+    /// Instructions SUPER-CHIP added, which the COSMAC VIP's interpreter does not have
+    pub(super) fn is_superchip(&self) -> bool {
+        matches!(
+            self,
+            Operation::SCD(_)
+                | Operation::SCR(_)
+                | Operation::SCL(_)
+                | Operation::EXIT(_)
+                | Operation::LOW(_)
+                | Operation::HIGH(_)
+                | Operation::LDHF(_)
+                | Operation::LDRV(_)
+                | Operation::LDVR(_)
+        ) || self.requires_high_res()
+    }
+
+    /// SUPER-CHIP instructions which only make sense on the 128 x 64 high resolution display
+    pub(super) fn requires_high_res(&self) -> bool {
+        // Dxy0 draws a 16 x 16 sprite
+        matches!(self, Operation::DRW(o) if o.n == 0)
+    }
+
+    /// Approximate COSMAC VIP machine cycles, 8 clock cycles each, including fetch and decode
+    pub(super) fn machine_cycles(&self) -> u32 {
+        match self {
+            Operation::LD1(_) => 6,
+            Operation::ADD1(_)
+            | Operation::LDVDT(_)
+            | Operation::LDK(_)
+            | Operation::LDDTV(_)
+            | Operation::LDST(_) => 10,
+            Operation::SE1(_) | Operation::SNE1(_) | Operation::LDI(_) => 12,
+            Operation::SE2(_) | Operation::SNE2(_) | Operation::SKP(_) | Operation::SKNP(_) => 16,
+            Operation::ADDI(_) => 19,
+            Operation::LDF(_) | Operation::LDHF(_) => 20,
+            Operation::RET(_)
+            | Operation::SCD(_)
+            | Operation::SCR(_)
+            | Operation::SCL(_)
+            | Operation::EXIT(_)
+            | Operation::LOW(_)
+            | Operation::HIGH(_)
+            | Operation::LDRV(_)
+            | Operation::LDVR(_)
+            | Operation::SYS(_)
+            | Operation::JP(_)
+            | Operation::CALL(_)
+            | Operation::JPV0(_)
+            | Operation::UNKNOWN(_) => 23,
+            Operation::CLS(_) => 24,
+            Operation::RND(_) => 36,
+            Operation::LD2(_)
+            | Operation::OR(_)
+            | Operation::AND2(_)
+            | Operation::XOR(_)
+            | Operation::ADD2(_)
+            | Operation::SUB(_)
+            | Operation::SHR(_)
+            | Operation::SUBN(_)
+            | Operation::SHL(_) => 44,
+            Operation::LDIV(_) | Operation::LDVI(_) => 133,
+            Operation::LDB(_) => 204,
+            // the VIP waits for the display interrupt before drawing, so at most one sprite a frame
+            Operation::DRW(_) => 3668,
+        }
+    }
+}
+
+/// Mnemonics as written in Cowgod's Chip-8 Technical Reference
+impl std::fmt::Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::SCD(o) => write!(f, "SCD 0x{:X}", o.n),
+            Operation::CLS(_) => write!(f, "CLS"),
+            Operation::RET(_) => write!(f, "RET"),
+            Operation::SCR(_) => write!(f, "SCR"),
+            Operation::SCL(_) => write!(f, "SCL"),
+            Operation::EXIT(_) => write!(f, "EXIT"),
+            Operation::LOW(_) => write!(f, "LOW"),
+            Operation::HIGH(_) => write!(f, "HIGH"),
+            Operation::SYS(o) => write!(f, "SYS 0x{:03X}", o.nnn),
+            Operation::JP(o) => write!(f, "JP 0x{:03X}", o.nnn),
+            Operation::CALL(o) => write!(f, "CALL 0x{:03X}", o.nnn),
+            Operation::SE1(o) => write!(f, "SE V{:X}, 0x{:02X}", o.x, o.nn),
+            Operation::SNE1(o) => write!(f, "SNE V{:X}, 0x{:02X}", o.x, o.nn),
+            Operation::SE2(o) => write!(f, "SE V{:X}, V{:X}", o.x, o.y),
+            Operation::LD1(o) => write!(f, "LD V{:X}, 0x{:02X}", o.x, o.nn),
+            Operation::ADD1(o) => write!(f, "ADD V{:X}, 0x{:02X}", o.x, o.nn),
+            Operation::LD2(o) => write!(f, "LD V{:X}, V{:X}", o.x, o.y),
+            Operation::OR(o) => write!(f, "OR V{:X}, V{:X}", o.x, o.y),
+            Operation::AND2(o) => write!(f, "AND V{:X}, V{:X}", o.x, o.y),
+            Operation::XOR(o) => write!(f, "XOR V{:X}, V{:X}", o.x, o.y),
+            Operation::ADD2(o) => write!(f, "ADD V{:X}, V{:X}", o.x, o.y),
+            Operation::SUB(o) => write!(f, "SUB V{:X}, V{:X}", o.x, o.y),
+            Operation::SHR(o) => write!(f, "SHR V{:X}, V{:X}", o.x, o.y),
+            Operation::SUBN(o) => write!(f, "SUBN V{:X}, V{:X}", o.x, o.y),
+            Operation::SHL(o) => write!(f, "SHL V{:X}, V{:X}", o.x, o.y),
+            Operation::SNE2(o) => write!(f, "SNE V{:X}, V{:X}", o.x, o.y),
+            Operation::LDI(o) => write!(f, "LD I, 0x{:03X}", o.nnn),
+            Operation::JPV0(o) => write!(f, "JP V0, 0x{:03X}", o.nnn),
+            Operation::RND(o) => write!(f, "RND V{:X}, 0x{:02X}", o.x, o.nn),
+            Operation::DRW(o) => write!(f, "DRW V{:X}, V{:X}, 0x{:X}", o.x, o.y, o.n),
+            Operation::SKP(o) => write!(f, "SKP V{:X}", o.x),
+            Operation::SKNP(o) => write!(f, "SKNP V{:X}", o.x),
+            Operation::LDVDT(o) => write!(f, "LD V{:X}, DT", o.x),
+            Operation::LDK(o) => write!(f, "LD V{:X}, K", o.x),
+            Operation::LDDTV(o) => write!(f, "LD DT, V{:X}", o.x),
+            Operation::LDST(o) => write!(f, "LD ST, V{:X}", o.x),
+            Operation::ADDI(o) => write!(f, "ADD I, V{:X}", o.x),
+            Operation::LDF(o) => write!(f, "LD F, V{:X}", o.x),
+            Operation::LDHF(o) => write!(f, "LD HF, V{:X}", o.x),
+            Operation::LDB(o) => write!(f, "LD B, V{:X}", o.x),
+            Operation::LDIV(o) => write!(f, "LD [I], V{:X}", o.x),
+            Operation::LDVI(o) => write!(f, "LD V{:X}, [I]", o.x),
+            Operation::LDRV(o) => write!(f, "LD R, V{:X}", o.x),
+            Operation::LDVR(o) => write!(f, "LD V{:X}, R", o.x),
+            Operation::UNKNOWN(o) => write!(f, "UNKNOWN 0x{:04X}", o.opcode),
         }
     }
 }
 
+impl Operation {
+    /// Opcode of the instruction, the inverse of [`Operation::parse`]
+    pub(super) fn to_bytes(&self) -> [u8; 2] {
+        let opcode = match self {
+            Operation::SCD(o) => 0x00C0 | u16::from(o.n),
+            Operation::CLS(_) => 0x00E0,
+            Operation::RET(_) => 0x00EE,
+            Operation::SCR(_) => 0x00FB,
+            Operation::SCL(_) => 0x00FC,
+            Operation::EXIT(_) => 0x00FD,
+            Operation::LOW(_) => 0x00FE,
+            Operation::HIGH(_) => 0x00FF,
+            Operation::SYS(o) => o.nnn,
+            Operation::JP(o) => 0x1000 | o.nnn,
+            Operation::CALL(o) => 0x2000 | o.nnn,
+            Operation::SE1(o) => 0x3000 | nibble::from_xnn(o.x, o.nn),
+            Operation::SNE1(o) => 0x4000 | nibble::from_xnn(o.x, o.nn),
+            Operation::SE2(o) => 0x5000 | nibble::from_xyn(o.x, o.y, 0x0),
+            Operation::LD1(o) => 0x6000 | nibble::from_xnn(o.x, o.nn),
+            Operation::ADD1(o) => 0x7000 | nibble::from_xnn(o.x, o.nn),
+            Operation::LD2(o) => 0x8000 | nibble::from_xyn(o.x, o.y, 0x0),
+            Operation::OR(o) => 0x8000 | nibble::from_xyn(o.x, o.y, 0x1),
+            Operation::AND2(o) => 0x8000 | nibble::from_xyn(o.x, o.y, 0x2),
+            Operation::XOR(o) => 0x8000 | nibble::from_xyn(o.x, o.y, 0x3),
+            Operation::ADD2(o) => 0x8000 | nibble::from_xyn(o.x, o.y, 0x4),
+            Operation::SUB(o) => 0x8000 | nibble::from_xyn(o.x, o.y, 0x5),
+            Operation::SHR(o) => 0x8000 | nibble::from_xyn(o.x, o.y, 0x6),
+            Operation::SUBN(o) => 0x8000 | nibble::from_xyn(o.x, o.y, 0x7),
+            Operation::SHL(o) => 0x8000 | nibble::from_xyn(o.x, o.y, 0xE),
+            Operation::SNE2(o) => 0x9000 | nibble::from_xyn(o.x, o.y, 0x0),
+            Operation::LDI(o) => 0xA000 | o.nnn,
+            Operation::JPV0(o) => 0xB000 | o.nnn,
+            Operation::RND(o) => 0xC000 | nibble::from_xnn(o.x, o.nn),
+            Operation::DRW(o) => 0xD000 | nibble::from_xyn(o.x, o.y, o.n),
+            Operation::SKP(o) => 0xE09E | nibble::from_xnn(o.x, 0x00),
+            Operation::SKNP(o) => 0xE0A1 | nibble::from_xnn(o.x, 0x00),
+            Operation::LDVDT(o) => 0xF007 | nibble::from_xnn(o.x, 0x00),
+            Operation::LDK(o) => 0xF00A | nibble::from_xnn(o.x, 0x00),
+            Operation::LDDTV(o) => 0xF015 | nibble::from_xnn(o.x, 0x00),
+            Operation::LDST(o) => 0xF018 | nibble::from_xnn(o.x, 0x00),
+            Operation::ADDI(o) => 0xF01E | nibble::from_xnn(o.x, 0x00),
+            Operation::LDF(o) => 0xF029 | nibble::from_xnn(o.x, 0x00),
+            Operation::LDHF(o) => 0xF030 | nibble::from_xnn(o.x, 0x00),
+            Operation::LDB(o) => 0xF033 | nibble::from_xnn(o.x, 0x00),
+            Operation::LDIV(o) => 0xF055 | nibble::from_xnn(o.x, 0x00),
+            Operation::LDVI(o) => 0xF065 | nibble::from_xnn(o.x, 0x00),
+            Operation::LDRV(o) => 0xF075 | nibble::from_xnn(o.x, 0x00),
+            Operation::LDVR(o) => 0xF085 | nibble::from_xnn(o.x, 0x00),
+            Operation::UNKNOWN(o) => o.opcode,
+        };
+
+        opcode.to_be_bytes()
+    }
+}
+
+impl SCD {
+    pub(super) fn new(n: u8) -> SCD {
+        SCD { n }
+    }
+
+    pub(super) fn execute(&self, register: &mut Register, display: &mut Display) {
+        display.scroll_down(self.n);
+        register.increment_program_counter();
+    }
+}
+
 impl CLS {
     pub(super) fn new() -> CLS {
         CLS
@@ -487,14 +782,63 @@ impl RET {
     }
 }
 
-impl SYS {
-    pub(super) fn new(nnn: u16) -> SYS {
-        SYS { nnn }
+impl SCR {
+    pub(super) fn new() -> SCR {
+        SCR
+    }
+
+    #[allow(clippy::unused_self)]
+    pub(super) fn execute(&self, register: &mut Register, display: &mut Display) {
+        display.scroll_right();
+        register.increment_program_counter();
+    }
+}
+
+impl SCL {
+    pub(super) fn new() -> SCL {
+        SCL
+    }
+
+    #[allow(clippy::unused_self)]
+    pub(super) fn execute(&self, register: &mut Register, display: &mut Display) {
+        display.scroll_left();
+        register.increment_program_counter();
+    }
+}
+
+impl EXIT {
+    pub(super) fn new() -> EXIT {
+        EXIT
+    }
+}
+
+impl LOW {
+    pub(super) fn new() -> LOW {
+        LOW
+    }
+
+    #[allow(clippy::unused_self)]
+    pub(super) fn execute(&self, register: &mut Register, display: &mut Display) {
+        display.set_high_res(false);
+        register.increment_program_counter();
+    }
+}
+
+impl HIGH {
+    pub(super) fn new() -> HIGH {
+        HIGH
     }
 
     #[allow(clippy::unused_self)]
-    pub(super) fn execute(&self) {
-        todo!();
+    pub(super) fn execute(&self, register: &mut Register, display: &mut Display) {
+        display.set_high_res(true);
+        register.increment_program_counter();
+    }
+}
+
+impl SYS {
+    pub(super) fn new(nnn: u16) -> SYS {
+        SYS { nnn }
     }
 }
 
@@ -503,6 +847,10 @@ impl JP {
         JP { nnn }
     }
 
+    pub(super) fn nnn(&self) -> u16 {
+        self.nnn
+    }
+
     pub(super) fn execute(&self, register: &mut Register) {
         register.set_program_counter(self.nnn);
     }
@@ -513,6 +861,10 @@ impl CALL {
         CALL { nnn }
     }
 
+    pub(super) fn nnn(&self) -> u16 {
+        self.nnn
+    }
+
     pub(super) fn execute(&self, register: &mut Register) {
         register.push_stack(register.get_program_counter());
         register.set_program_counter(self.nnn);
@@ -597,8 +949,12 @@ impl OR {
         OR { x, y }
     }
 
-    pub(super) fn execute(&self, register: &mut Register) {
+    pub(super) fn execute(&self, register: &mut Register, quirks: Quirks) {
         register.set_v(self.x, register.get_v(self.x) | register.get_v(self.y));
+        // VF is reset last, so it ends at 0 even when it is also Vx
+        if quirks.logic_resets_vf {
+            register.set_v(0xF, 0x0);
+        }
         register.increment_program_counter();
     }
 }
@@ -608,8 +964,12 @@ impl AND2 {
         AND2 { x, y }
     }
 
-    pub(super) fn execute(&self, register: &mut Register) {
+    pub(super) fn execute(&self, register: &mut Register, quirks: Quirks) {
         register.set_v(self.x, register.get_v(self.x) & register.get_v(self.y));
+        // VF is reset last, so it ends at 0 even when it is also Vx
+        if quirks.logic_resets_vf {
+            register.set_v(0xF, 0x0);
+        }
         register.increment_program_counter();
     }
 }
@@ -619,8 +979,12 @@ impl XOR {
         XOR { x, y }
     }
 
-    pub(super) fn execute(&self, register: &mut Register) {
+    pub(super) fn execute(&self, register: &mut Register, quirks: Quirks) {
         register.set_v(self.x, register.get_v(self.x) ^ register.get_v(self.y));
+        // VF is reset last, so it ends at 0 even when it is also Vx
+        if quirks.logic_resets_vf {
+            register.set_v(0xF, 0x0);
+        }
         register.increment_program_counter();
     }
 }
@@ -635,13 +999,9 @@ impl ADD2 {
             .get_v(self.x)
             .overflowing_add(register.get_v(self.y));
 
-        if overflow {
-            register.set_v(0xF, 0x1);
-        } else {
-            register.set_v(0xF, 0x0);
-        }
-
+        // VF is written last, so it holds the carry even when it is also Vx
         register.set_v(self.x, nn);
+        register.set_v(0xF, u8::from(overflow));
         register.increment_program_counter();
     }
 }
@@ -672,19 +1032,16 @@ impl SHR {
         SHR { x, y }
     }
 
-    pub(super) fn execute(&self, register: &mut Register) {
-        // TODO: optional Vx = Vy
-
-        let vx = register.get_v(self.x);
-
-        if vx & 0b0000_0001 == 0b0000_0001 {
-            register.set_v(0xF, 0x1);
+    pub(super) fn execute(&self, register: &mut Register, quirks: Quirks) {
+        let vx = if quirks.shift_in_place {
+            register.get_v(self.x)
         } else {
-            register.set_v(0xF, 0x0);
-        }
+            register.get_v(self.y)
+        };
 
-        let (nn, _) = vx.overflowing_shr(0x1);
-        register.set_v(self.x, nn);
+        register.set_v(self.x, vx >> 1);
+        // written last so the flag wins when x is F
+        register.set_v(0xF, vx & 0b0000_0001);
         register.increment_program_counter();
     }
 }
@@ -715,19 +1072,16 @@ impl SHL {
         SHL { x, y }
     }
 
-    pub(super) fn execute(&self, register: &mut Register) {
-        // TODO: optional Vx = Vy
-
-        let vx = register.get_v(self.x);
-
-        if vx & 0b1000_0000 == 0b1000_0000 {
-            register.set_v(0xF, 0x1);
+    pub(super) fn execute(&self, register: &mut Register, quirks: Quirks) {
+        let vx = if quirks.shift_in_place {
+            register.get_v(self.x)
         } else {
-            register.set_v(0xF, 0x0);
-        }
+            register.get_v(self.y)
+        };
 
-        let (nn, _) = vx.overflowing_shl(0x1);
-        register.set_v(self.x, nn);
+        register.set_v(self.x, vx << 1);
+        // written last so the flag wins when x is F
+        register.set_v(0xF, vx >> 7);
         register.increment_program_counter();
     }
 }
@@ -761,8 +1115,15 @@ impl JPV0 {
         JPV0 { nnn }
     }
 
-    pub(super) fn execute(&self, register: &mut Register) {
-        register.set_program_counter(self.nnn + u16::from(register.get_v(0x0)));
+    pub(super) fn execute(&self, register: &mut Register, quirks: Quirks) {
+        #[allow(clippy::cast_possible_truncation)]
+        let x = if quirks.jump_with_vx {
+            (self.nnn >> 8) as u8
+        } else {
+            0x0
+        };
+
+        register.set_program_counter(self.nnn + u16::from(register.get_v(x)));
     }
 }
 
@@ -782,56 +1143,103 @@ impl DRW {
         DRW { x, y, n }
     }
 
-    /// TODO: impl wrap around
+    /// Address of a sprite byte `offset` bytes past I, which may run past the end of memory
+    pub(super) fn sprite_address(i: u16, offset: u8, quirks: Quirks) -> u16 {
+        let address = i.wrapping_add(u16::from(offset));
+
+        if quirks.wrap_sprite_reads {
+            address & 0x0FFF
+        } else {
+            address.min(0x0FFF)
+        }
+    }
+
+    /// Byte of a sprite `offset` bytes past I, as read by the quirks
+    pub(super) fn sprite_byte(memory: &Memory, i: u16, offset: u8, quirks: Quirks) -> u8 {
+        let address = DRW::sprite_address(i, offset, quirks);
+
+        if quirks.zero_reads_past_rom && address >= memory.rom_end() {
+            0x00
+        } else {
+            memory.get_byte(address)
+        }
+    }
+
     pub(super) fn execute(
         &self,
         register: &mut Register,
         display: &mut Display,
         memory: &mut Memory,
+        quirks: Quirks,
     ) {
-        // Set the X coordinate to the value in VX modulo 64
-        let x = register.get_v(self.x) % 64;
-        // Set the Y coordinate to the value in VY modulo 32
-        let y = register.get_v(self.y) % 32;
+        let (width, height) = (display.width(), display.height());
+
+        // Set the X coordinate to the value in VX modulo the display width
+        let x = register.get_v(self.x) % width;
+        // Set the Y coordinate to the value in VY modulo the display height
+        let y = register.get_v(self.y) % height;
+
+        // Extended screen mode draws 16 x 16 sprites for N = 0, otherwise N rows of 8 pixels
+        let (rows, columns) = if self.n == 0 && display.is_high_res() {
+            (16, 16)
+        } else {
+            (self.n, 8)
+        };
 
         // Set VF to 0
         register.set_v(0xF, 0);
 
-        // For N rows
-        for row in 0..self.n {
-            // Get the Nth byte of sprite data, counting from the memory address in the I register
-            let sprite_data = memory.get_byte(register.get_i() + u16::from(row));
+        // For each row
+        for row in 0..rows {
+            // If you reach the bottom edge of the screen, stop drawing or wrap to the top
+            if y + row >= height && quirks.clip_sprites {
+                break;
+            }
+            let pixel_y = (y + row) % height;
+
+            for byte in 0..columns / 8 {
+                memory.note_read(DRW::sprite_address(
+                    register.get_i(),
+                    row * (columns / 8) + byte,
+                    quirks,
+                ));
+            }
+
+            // For each of the pixels/bits in this sprite row
+            for pixel in 0..columns {
+                // If you reach the right edge of the screen, stop drawing this row or wrap to the left
+                if x + pixel >= width && quirks.clip_sprites {
+                    break;
+                }
+                let pixel_x = (x + pixel) % width;
+
+                // Get the byte of sprite data holding the pixel, counting from the memory address in the I register
+                let sprite_data = DRW::sprite_byte(
+                    memory,
+                    register.get_i(),
+                    row * (columns / 8) + pixel / 8,
+                    quirks,
+                );
 
-            // For each of the 8 pixels/bits in this sprite row
-            for pixel in 0..8 {
-                let sprite_row_pixel = match (sprite_data >> (7 - pixel)) & 0x1 {
+                let sprite_row_pixel = match (sprite_data >> (7 - pixel % 8)) & 0x1 {
                     1 => true,
                     0 => false,
                     v => panic!("{}", v),
                 };
-                let display_pixel = display.is_pixel_on(x + pixel, y + row);
+                let display_pixel = display.is_pixel_on(pixel_x, pixel_y);
 
                 // If the current pixel in the sprite row is on and the pixel at coordinates X,Y on the screen is also on
                 if sprite_row_pixel && display_pixel {
                     // turn off the pixel
-                    display.set_pixel(x + pixel, y + row, false);
+                    display.set_pixel(pixel_x, pixel_y, false);
                     // set VF to 1
                     register.set_v(0xF, 1);
                 }
                 // Or if the current pixel in the sprite row is on and the screen pixel is not
                 else if sprite_row_pixel && !display_pixel {
                     // draw the pixel at the X and Y coordinates
-                    display.set_pixel(x + pixel, y + row, sprite_row_pixel);
+                    display.set_pixel(pixel_x, pixel_y, sprite_row_pixel);
                 }
-
-                // If you reach the right edge of the screen, stop drawing this row
-                if x + pixel == 63 {
-                    break;
-                };
-            }
-
-            if y + row == 31 {
-                break;
             }
         }
 
@@ -871,7 +1279,7 @@ impl LDVDT {
     }
 
     pub(super) fn execute(&self, register: &mut Register, delay_timer: &Timer) {
-        register.set_v(self.x, delay_timer.get());
+        register.set_v(self.x, delay_timer.value());
         register.increment_program_counter();
     }
 }
@@ -881,10 +1289,21 @@ impl LDK {
         LDK { x }
     }
 
-    pub(super) fn execute(&self, register: &mut Register, keypad: &Keypad) {
-        if let Some(n) = keypad.read() {
-            register.set_v(self.x, n);
-            register.increment_program_counter();
+    /// Waits for a key to be pressed and then released, so a held key isn't read again by the next `Fx0A`
+    pub(super) fn execute(
+        &self,
+        register: &mut Register,
+        keypad: &Keypad,
+        pending_key: &mut Option<u8>,
+    ) {
+        match *pending_key {
+            None => *pending_key = keypad.read(),
+            Some(n) if !keypad.is_pressed(n) => {
+                *pending_key = None;
+                register.set_v(self.x, n);
+                register.increment_program_counter();
+            }
+            Some(_) => (),
         }
     }
 }
@@ -916,8 +1335,14 @@ impl ADDI {
         ADDI { x }
     }
 
-    pub(super) fn execute(&self, register: &mut Register) {
-        register.set_i(register.get_i() + u16::from(register.get_v(self.x)));
+    pub(super) fn execute(&self, register: &mut Register, quirks: Quirks) {
+        let i = register
+            .get_i()
+            .wrapping_add(u16::from(register.get_v(self.x)));
+        register.set_i(i);
+        if quirks.add_to_index_sets_vf {
+            register.set_v(0xF, u8::from(i > 0x0FFF));
+        }
         register.increment_program_counter();
     }
 }
@@ -933,19 +1358,29 @@ impl LDF {
     }
 }
 
+impl LDHF {
+    pub(super) fn new(x: u8) -> LDHF {
+        LDHF { x }
+    }
+
+    pub(super) fn execute(&self, register: &mut Register) {
+        register.set_i(0x0A0 + u16::from(register.get_v(self.x)) * 0xA);
+        register.increment_program_counter();
+    }
+}
+
 impl LDB {
     pub(super) fn new(x: u8) -> LDB {
         LDB { x }
     }
 
-    #[allow(clippy::cast_possible_truncation)]
     pub(super) fn execute(&self, register: &mut Register, memory: &mut Memory) {
-        // TODO: do not rely on string conversion
-        let string = format!("{:03}", register.get_v(self.x));
+        let vx = register.get_v(self.x);
+        let i = register.get_i();
 
-        for (i, c) in string.chars().enumerate() {
-            memory.set_byte(register.get_i() + i as u16, c.to_digit(10).unwrap() as u8);
-        }
+        memory.set_byte(i, vx / 100);
+        memory.set_byte(i.wrapping_add(1), vx / 10 % 10);
+        memory.set_byte(i.wrapping_add(2), vx % 10);
         register.increment_program_counter();
     }
 }
@@ -955,9 +1390,15 @@ impl LDIV {
         LDIV { x }
     }
 
-    pub(super) fn execute(&self, register: &mut Register, memory: &mut Memory) {
+    pub(super) fn execute(&self, register: &mut Register, memory: &mut Memory, quirks: Quirks) {
         for x in 0..=self.x {
-            memory.set_byte(register.get_i() + u16::from(x), register.get_v(x));
+            memory.set_byte(
+                register.get_i().wrapping_add(u16::from(x)),
+                register.get_v(x),
+            );
+        }
+        if quirks.memory_increment {
+            register.set_i(register.get_i().wrapping_add(u16::from(self.x) + 1));
         }
         register.increment_program_counter();
     }
@@ -968,17 +1409,53 @@ impl LDVI {
         LDVI { x }
     }
 
-    pub(super) fn execute(&self, register: &mut Register, memory: &mut Memory) {
+    pub(super) fn execute(&self, register: &mut Register, memory: &mut Memory, quirks: Quirks) {
         for x in 0..=self.x {
-            let byte = memory.get_byte(register.get_i() + u16::from(x));
+            let byte = memory.read_byte(register.get_i().wrapping_add(u16::from(x)));
             register.set_v(x, byte);
         }
+        if quirks.memory_increment {
+            register.set_i(register.get_i().wrapping_add(u16::from(self.x) + 1));
+        }
+        register.increment_program_counter();
+    }
+}
+
+impl LDRV {
+    pub(super) fn new(x: u8) -> LDRV {
+        LDRV { x }
+    }
+
+    pub(super) fn execute(&self, register: &mut Register) {
+        for x in 0..=self.x.min(7) {
+            register.set_rpl(x, register.get_v(x));
+        }
+        register.increment_program_counter();
+    }
+}
+
+impl LDVR {
+    pub(super) fn new(x: u8) -> LDVR {
+        LDVR { x }
+    }
+
+    pub(super) fn execute(&self, register: &mut Register) {
+        for x in 0..=self.x.min(7) {
+            register.set_v(x, register.get_rpl(x));
+        }
         register.increment_program_counter();
     }
 }
 
-mod nibble {
-    pub(super) fn from_bytes(bytes: [u8; 2]) -> [u8; 4] {
+impl UNKNOWN {
+    pub(super) fn new(opcode: u16) -> UNKNOWN {
+        UNKNOWN { opcode }
+    }
+}
+
+/// Splits opcodes into nibbles and joins them back, shared by everything that decodes opcodes
+pub(crate) mod nibble {
+    pub(crate) fn from_bytes(bytes: [u8; 2]) -> [u8; 4] {
         [
             (bytes[0] & 0xF0) >> 4,
             bytes[0] & 0x0F,
@@ -987,16 +1464,24 @@ mod nibble {
         ]
     }
 
-    pub(super) fn to_n(n: u8) -> u8 {
-        n
+    pub(crate) fn to_n(n: u8) -> u8 {
+        n & 0x0F
+    }
+
+    pub(crate) fn to_nn(n3: u8, n4: u8) -> u8 {
+        (to_n(n3) << 4) | to_n(n4)
+    }
+
+    pub(crate) fn to_nnn(n2: u8, n3: u8, n4: u8) -> u16 {
+        (u16::from(to_n(n2)) << 8) | u16::from(to_nn(n3, n4))
     }
 
-    pub(super) fn to_nn(n3: u8, n4: u8) -> u8 {
-        (n3 << 4) + n4
+    pub(crate) fn from_xnn(x: u8, nn: u8) -> u16 {
+        (u16::from(x) << 8) | u16::from(nn)
     }
 
-    pub(super) fn to_nnn(n2: u8, n3: u8, n4: u8) -> u16 {
-        (u16::from(n2) << 8) + (u16::from(n3) << 4) + (u16::from(n4))
+    pub(crate) fn from_xyn(x: u8, y: u8, n: u8) -> u16 {
+        (u16::from(x) << 8) | (u16::from(y) << 4) | u16::from(n)
     }
 }
 
@@ -1004,6 +1489,15 @@ mod nibble {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_nibble() {
+        assert_eq!(nibble::from_bytes([0xAB, 0xCD]), [0xA, 0xB, 0xC, 0xD]);
+        assert_eq!(nibble::to_nn(0xF, 0xF), 0xFF);
+        assert_eq!(nibble::to_nn(0x0, 0x0), 0x00);
+        assert_eq!(nibble::to_nnn(0xF, 0xF, 0xF), 0xFFF);
+        assert_eq!(nibble::to_nnn(0x1, 0x2, 0x3), 0x123);
+    }
+
     #[test]
     fn test_cls() {
         // Arrange
@@ -1023,73 +1517,157 @@ mod tests {
     }
 
     #[test]
-    fn test_ret() {
+    fn test_scd() {
         // Arrange
+        let mut display = Display::new();
         let mut register = Register::new();
-        register.push_stack(0x400);
-        register.push_stack(0x600);
-
-        let instruction = RET::new();
-
-        // Act
-        instruction.execute(&mut register);
-
-        // Assert
-        assert_eq!(register.get_program_counter(), 0x602);
+        display.set_pixel(5, 0, true);
+        display.set_pixel(5, 30, true);
+        let instruction = SCD::new(0x3);
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, &mut display);
 
         // Assert
-        assert_eq!(register.get_program_counter(), 0x402);
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert!(display.is_pixel_on(5, 3));
+        assert!(!display.is_pixel_on(5, 0));
+        assert!(!display.is_pixel_on(5, 30), "scrolled off the bottom edge");
+        assert!((0..3).all(|y| display.get_row(y) == 0));
     }
 
     #[test]
-    #[should_panic]
-    fn test_sys() {
+    fn test_scr() {
         // Arrange
-        let instruction = SYS::new(000);
+        let mut display = Display::new();
+        let mut register = Register::new();
+        display.set_pixel(0, 7, true);
+        display.set_pixel(62, 7, true);
+        let instruction = SCR::new();
 
         // Act
-        instruction.execute();
+        instruction.execute(&mut register, &mut display);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert!(display.is_pixel_on(4, 7));
+        assert!(!display.is_pixel_on(0, 7));
+        assert!(!display.is_pixel_on(2, 7), "scrolled off the right edge");
+        assert_eq!(display.get_row(7), 1 << 59);
     }
 
     #[test]
-    fn test_jp() {
+    fn test_scl() {
         // Arrange
+        let mut display = Display::new();
         let mut register = Register::new();
-        let instruction = JP::new(0x400);
+        display.set_pixel(1, 7, true);
+        display.set_pixel(63, 7, true);
+        let instruction = SCL::new();
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, &mut display);
 
         // Assert
-        assert_eq!(register.get_program_counter(), 0x400);
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert!(display.is_pixel_on(59, 7));
+        assert!(!display.is_pixel_on(63, 7));
+        assert_eq!(display.get_row(7), 1 << 4);
     }
 
     #[test]
-    fn test_call() {
+    fn test_high() {
         // Arrange
+        let mut display = Display::new();
         let mut register = Register::new();
-        let instruction = CALL::new(0x400);
+        display.set_pixel(0, 0, true);
+        let instruction = HIGH::new();
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, &mut display);
 
         // Assert
-        assert_eq!(register.get_program_counter(), 0x400);
-        assert_eq!(register.pop_stack(), 0x200);
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert!(display.is_high_res());
+        assert!(!display.is_pixel_on(0, 0));
     }
 
     #[test]
-    fn test_se1_equal() {
+    fn test_low() {
         // Arrange
+        let mut display = Display::new();
         let mut register = Register::new();
-        register.set_v(0x4, 0x2);
-        let instruction = SE1::new(0x4, 0x2);
+        display.set_high_res(true);
+        display.set_pixel(0, 0, true);
+        let instruction = LOW::new();
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, &mut display);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert!(!display.is_high_res());
+        assert!(!display.is_pixel_on(0, 0));
+    }
+
+    #[test]
+    fn test_ret() {
+        // Arrange
+        let mut register = Register::new();
+        register.push_stack(0x400);
+        register.push_stack(0x600);
+
+        let instruction = RET::new();
+
+        // Act
+        instruction.execute(&mut register);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x602);
+
+        // Act
+        instruction.execute(&mut register);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x402);
+    }
+
+    #[test]
+    fn test_jp() {
+        // Arrange
+        let mut register = Register::new();
+        let instruction = JP::new(0x400);
+
+        // Act
+        instruction.execute(&mut register);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x400);
+    }
+
+    #[test]
+    fn test_call() {
+        // Arrange
+        let mut register = Register::new();
+        let instruction = CALL::new(0x400);
+
+        // Act
+        instruction.execute(&mut register);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x400);
+        assert_eq!(register.pop_stack(), 0x200);
+    }
+
+    #[test]
+    fn test_se1_equal() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x4, 0x2);
+        let instruction = SE1::new(0x4, 0x2);
+
+        // Act
+        instruction.execute(&mut register);
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x204);
@@ -1236,13 +1814,53 @@ mod tests {
         let instruction = OR::new(0x4, 0x7);
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, Quirks::default());
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
         assert_eq!(register.get_v(0x4), 0b1111_0101);
     }
 
+    #[test]
+    fn test_or_vf() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x7, 0b0101_0101);
+        register.set_v(0xF, 0b1010_0101);
+        let instruction = OR::new(0xF, 0x7);
+        let quirks = Quirks {
+            logic_resets_vf: false,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0xF), 0b1111_0101);
+    }
+
+    #[test]
+    fn test_or_logic_resets_vf() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x7, 0b0101_0101);
+        register.set_v(0xF, 0b1010_0101);
+        let instruction = OR::new(0xF, 0x7);
+        let quirks = Quirks {
+            logic_resets_vf: true,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0xF), 0x0);
+    }
+
     #[test]
     fn test_and2() {
         // Arrange
@@ -1252,13 +1870,53 @@ mod tests {
         let instruction = AND2::new(0x4, 0x7);
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, Quirks::default());
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
         assert_eq!(register.get_v(0x4), 0b0000_0101);
     }
 
+    #[test]
+    fn test_and2_vf() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x7, 0b0101_0101);
+        register.set_v(0xF, 0b1010_0101);
+        let instruction = AND2::new(0xF, 0x7);
+        let quirks = Quirks {
+            logic_resets_vf: false,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0xF), 0b0000_0101);
+    }
+
+    #[test]
+    fn test_and2_logic_resets_vf() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x7, 0b0101_0101);
+        register.set_v(0xF, 0b1010_0101);
+        let instruction = AND2::new(0xF, 0x7);
+        let quirks = Quirks {
+            logic_resets_vf: true,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0xF), 0x0);
+    }
+
     #[test]
     fn test_xor() {
         // Arrange
@@ -1268,13 +1926,53 @@ mod tests {
         let instruction = XOR::new(0x4, 0x7);
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, Quirks::default());
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
         assert_eq!(register.get_v(0x4), 0b1111_0000);
     }
 
+    #[test]
+    fn test_xor_vf() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x7, 0b0101_0101);
+        register.set_v(0xF, 0b1010_0101);
+        let instruction = XOR::new(0xF, 0x7);
+        let quirks = Quirks {
+            logic_resets_vf: false,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0xF), 0b1111_0000);
+    }
+
+    #[test]
+    fn test_xor_logic_resets_vf() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x7, 0b0101_0101);
+        register.set_v(0xF, 0b1010_0101);
+        let instruction = XOR::new(0xF, 0x7);
+        let quirks = Quirks {
+            logic_resets_vf: true,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0xF), 0x0);
+    }
+
     #[test]
     fn test_add2() {
         // Arrange
@@ -1309,6 +2007,38 @@ mod tests {
         assert_eq!(register.get_v(0xF), 0x1);
     }
 
+    #[test]
+    fn test_add2_vf() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0xF, 0xFF);
+        register.set_v(0x2, 0x02);
+        let instruction = ADD2::new(0xF, 0x2);
+
+        // Act
+        instruction.execute(&mut register);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0xF), 0x1);
+    }
+
+    #[test]
+    fn test_add2_vf_no_carry() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0xF, 0x07);
+        register.set_v(0x2, 0x03);
+        let instruction = ADD2::new(0xF, 0x2);
+
+        // Act
+        instruction.execute(&mut register);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0xF), 0x0);
+    }
+
     #[test]
     fn test_sub() {
         // Arrange
@@ -1351,7 +2081,7 @@ mod tests {
         let instruction = SHR::new(0x4, 0x2);
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, Quirks::default());
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
@@ -1367,7 +2097,7 @@ mod tests {
         let instruction = SHR::new(0x4, 0x2);
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, Quirks::default());
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
@@ -1375,6 +2105,134 @@ mod tests {
         assert_eq!(register.get_v(0xF), 0x1);
     }
 
+    #[test]
+    fn test_shift_source() {
+        for (shift_in_place, shr, shl) in [
+            (true, 0b0100_0000, 0b0000_0010),
+            (false, 0b0000_0011, 0b0000_1100),
+        ] {
+            // Arrange
+            let quirks = Quirks {
+                shift_in_place,
+                ..Quirks::default()
+            };
+            let mut right = Register::new();
+            right.set_v(0x4, 0b1000_0001);
+            right.set_v(0x2, 0b0000_0110);
+            let mut left = Register::new();
+            left.set_v(0x4, 0b1000_0001);
+            left.set_v(0x2, 0b0000_0110);
+
+            // Act
+            SHR::new(0x4, 0x2).execute(&mut right, quirks);
+            SHL::new(0x4, 0x2).execute(&mut left, quirks);
+
+            // Assert
+            assert_eq!(right.get_v(0x4), shr, "shift_in_place: {}", shift_in_place);
+            assert_eq!(left.get_v(0x4), shl, "shift_in_place: {}", shift_in_place);
+            assert_eq!(right.get_v(0x2), 0b0000_0110);
+            assert_eq!(left.get_v(0x2), 0b0000_0110);
+        }
+    }
+
+    #[test]
+    fn test_shr_vy() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x4, 0b1111_1010);
+        register.set_v(0x2, 0b0000_0101);
+        let instruction = SHR::new(0x4, 0x2);
+        let quirks = Quirks {
+            shift_in_place: false,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0x4), 0b0000_0010);
+        assert_eq!(register.get_v(0xF), 0x1);
+    }
+
+    /// V0 = 1000_0001, V1 = 0100_0010 and VF = 0000_0011, so every source shifts out a different flag
+    fn shift_registers() -> Register {
+        let mut register = Register::new();
+        register.set_v(0x0, 0b1000_0001);
+        register.set_v(0x1, 0b0100_0010);
+        register.set_v(0xF, 0b0000_0011);
+        register
+    }
+
+    #[test]
+    fn test_shr_vf_aliasing() {
+        // (shift_in_place, x, y, expected V0, expected VF)
+        let cases = [
+            (true, 0x0, 0x1, 0b0100_0000, 0x1),
+            (true, 0x0, 0xF, 0b0100_0000, 0x1),
+            (true, 0xF, 0x1, 0b1000_0001, 0x1),
+            (true, 0xF, 0xF, 0b1000_0001, 0x1),
+            (false, 0x0, 0x1, 0b0010_0001, 0x0),
+            (false, 0x0, 0xF, 0b0000_0001, 0x1),
+            (false, 0xF, 0x1, 0b1000_0001, 0x0),
+            (false, 0xF, 0xF, 0b1000_0001, 0x1),
+        ];
+
+        for (shift_in_place, x, y, v0, vf) in cases {
+            // Arrange
+            let mut register = shift_registers();
+            let instruction = SHR::new(x, y);
+            let quirks = Quirks {
+                shift_in_place,
+                ..Quirks::default()
+            };
+
+            // Act
+            instruction.execute(&mut register, quirks);
+
+            // Assert
+            let case = format!("shift_in_place: {}, SHR V{:X}, V{:X}", shift_in_place, x, y);
+            assert_eq!(register.get_v(0x0), v0, "{}", case);
+            assert_eq!(register.get_v(0x1), 0b0100_0010, "{}", case);
+            assert_eq!(register.get_v(0xF), vf, "{}", case);
+        }
+    }
+
+    #[test]
+    fn test_shl_vf_aliasing() {
+        // (shift_in_place, x, y, expected V0, expected VF)
+        let cases = [
+            (true, 0x0, 0x1, 0b0000_0010, 0x1),
+            (true, 0x0, 0xF, 0b0000_0010, 0x1),
+            (true, 0xF, 0x1, 0b1000_0001, 0x0),
+            (true, 0xF, 0xF, 0b1000_0001, 0x0),
+            (false, 0x0, 0x1, 0b1000_0100, 0x0),
+            (false, 0x0, 0xF, 0b0000_0110, 0x0),
+            (false, 0xF, 0x1, 0b1000_0001, 0x0),
+            (false, 0xF, 0xF, 0b1000_0001, 0x0),
+        ];
+
+        for (shift_in_place, x, y, v0, vf) in cases {
+            // Arrange
+            let mut register = shift_registers();
+            let instruction = SHL::new(x, y);
+            let quirks = Quirks {
+                shift_in_place,
+                ..Quirks::default()
+            };
+
+            // Act
+            instruction.execute(&mut register, quirks);
+
+            // Assert
+            let case = format!("shift_in_place: {}, SHL V{:X}, V{:X}", shift_in_place, x, y);
+            assert_eq!(register.get_v(0x0), v0, "{}", case);
+            assert_eq!(register.get_v(0x1), 0b0100_0010, "{}", case);
+            assert_eq!(register.get_v(0xF), vf, "{}", case);
+        }
+    }
+
     #[test]
     fn test_subn() {
         // Arrange
@@ -1417,7 +2275,7 @@ mod tests {
         let instruction = SHL::new(0x4, 0x2);
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, Quirks::default());
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
@@ -1433,7 +2291,7 @@ mod tests {
         let instruction = SHL::new(0x4, 0x2);
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, Quirks::default());
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
@@ -1441,6 +2299,27 @@ mod tests {
         assert_eq!(register.get_v(0xF), 0x1);
     }
 
+    #[test]
+    fn test_shl_vy() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x4, 0b0101_1111);
+        register.set_v(0x2, 0b1000_0001);
+        let instruction = SHL::new(0x4, 0x2);
+        let quirks = Quirks {
+            shift_in_place: false,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_v(0x4), 0b0000_0010);
+        assert_eq!(register.get_v(0xF), 0x1);
+    }
+
     #[test]
     fn test_sne2_equal() {
         // Arrange
@@ -1490,15 +2369,39 @@ mod tests {
         // Arrange
         let mut register = Register::new();
         register.set_v(0x0, 0x20);
+        register.set_v(0x4, 0x30);
         let instruction = JPV0::new(0x400);
+        let quirks = Quirks {
+            jump_with_vx: false,
+            ..Quirks::default()
+        };
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, quirks);
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x420);
     }
 
+    #[test]
+    fn test_jpv0_jump_with_vx() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x0, 0x20);
+        register.set_v(0x4, 0x30);
+        let instruction = JPV0::new(0x400);
+        let quirks = Quirks {
+            jump_with_vx: true,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x430);
+    }
+
     #[test]
     fn test_rnd() {
         // Arrange
@@ -1514,6 +2417,275 @@ mod tests {
         assert_ne!(register.get_v(0x4), 0xFF);
     }
 
+    fn sprite_rows(display: &Display, rows: u8) -> Vec<u8> {
+        (0..rows)
+            .map(|y| {
+                (0..8).fold(0, |byte, x| {
+                    (byte << 1) | u8::from(display.is_pixel_on(x, y))
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_drw_clip_sprites() {
+        // Arrange
+        let mut display = Display::new();
+        let mut memory = Memory::new();
+        let mut register = Register::new();
+        memory.load_rom(&[0xF0, 0xF0]).unwrap();
+        register.set_i(0x200);
+        register.set_v(0x0, 62);
+        register.set_v(0x1, 31);
+        let quirks = Quirks {
+            clip_sprites: true,
+            ..Quirks::default()
+        };
+        let instruction = DRW::new(0x0, 0x1, 0x2);
+
+        // Act
+        instruction.execute(&mut register, &mut display, &mut memory, quirks);
+
+        // Assert
+        assert!(display.is_pixel_on(62, 31));
+        assert!(display.is_pixel_on(63, 31));
+        assert!(!display.is_pixel_on(0, 31));
+        assert!(!display.is_pixel_on(1, 31));
+        assert!(!display.is_pixel_on(62, 0));
+        assert!(!display.is_pixel_on(0, 0));
+    }
+
+    #[test]
+    fn test_drw_wrap_sprites() {
+        // Arrange
+        let mut display = Display::new();
+        let mut memory = Memory::new();
+        let mut register = Register::new();
+        memory.load_rom(&[0xF0, 0xF0]).unwrap();
+        register.set_i(0x200);
+        register.set_v(0x0, 62);
+        register.set_v(0x1, 31);
+        let quirks = Quirks {
+            clip_sprites: false,
+            ..Quirks::default()
+        };
+        let instruction = DRW::new(0x0, 0x1, 0x2);
+
+        // Act
+        instruction.execute(&mut register, &mut display, &mut memory, quirks);
+
+        // Assert
+        for y in [31, 0] {
+            assert!(display.is_pixel_on(62, y));
+            assert!(display.is_pixel_on(63, y));
+            assert!(display.is_pixel_on(0, y));
+            assert!(display.is_pixel_on(1, y));
+            assert!(!display.is_pixel_on(2, y));
+        }
+        assert_eq!(register.get_v(0xF), 0x0);
+    }
+
+    #[test]
+    fn test_drw_wrap_sprites_collision() {
+        // Arrange
+        let mut display = Display::new();
+        let mut memory = Memory::new();
+        let mut register = Register::new();
+        memory.load_rom(&[0xF0]).unwrap();
+        display.set_pixel(1, 0, true);
+        register.set_i(0x200);
+        register.set_v(0x0, 62);
+        let quirks = Quirks {
+            clip_sprites: false,
+            ..Quirks::default()
+        };
+        let instruction = DRW::new(0x0, 0x1, 0x1);
+
+        // Act
+        instruction.execute(&mut register, &mut display, &mut memory, quirks);
+
+        // Assert
+        assert_eq!(register.get_v(0xF), 0x1);
+        assert!(display.is_pixel_on(62, 0));
+        assert!(display.is_pixel_on(63, 0));
+        assert!(display.is_pixel_on(0, 0));
+        assert!(!display.is_pixel_on(1, 0));
+    }
+
+    #[test]
+    fn test_drw_extended() {
+        // Arrange
+        let mut display = Display::new();
+        let mut memory = Memory::new();
+        let mut register = Register::new();
+        let sprite = (0..16u16)
+            .flat_map(|row| (0x8001u16 | 1 << row).to_be_bytes())
+            .collect::<Vec<_>>();
+        memory.load_rom(&sprite).unwrap();
+        display.set_high_res(true);
+        register.set_i(0x200);
+        register.set_v(0x0, 100);
+        register.set_v(0x1, 40);
+        let instruction = DRW::new(0x0, 0x1, 0x0);
+
+        // Act
+        instruction.execute(&mut register, &mut display, &mut memory, Quirks::default());
+
+        // Assert
+        for row in 0..16 {
+            for column in 0..16 {
+                assert_eq!(
+                    display.is_pixel_on(100 + column, 40 + row),
+                    column == 0 || column == 15 || column == 15 - row,
+                    "{}, {}",
+                    column,
+                    row
+                );
+            }
+        }
+        assert!(!display.is_pixel_on(116, 40));
+        assert!(!display.is_pixel_on(100, 56));
+        assert_eq!(register.get_v(0xF), 0x0);
+
+        // Act
+        instruction.execute(&mut register, &mut display, &mut memory, Quirks::default());
+
+        // Assert
+        assert!(!display.is_pixel_on(100, 40));
+        assert_eq!(register.get_v(0xF), 0x1);
+    }
+
+    #[test]
+    fn test_drw_high_res_edges() {
+        // Arrange
+        let mut display = Display::new();
+        let mut memory = Memory::new();
+        let mut register = Register::new();
+        memory.load_rom(&[0xF0, 0xF0]).unwrap();
+        display.set_high_res(true);
+        register.set_i(0x200);
+        register.set_v(0x0, 126 + 128);
+        register.set_v(0x1, 63);
+        let instruction = DRW::new(0x0, 0x1, 0x2);
+
+        // Act
+        instruction.execute(&mut register, &mut display, &mut memory, Quirks::default());
+
+        // Assert
+        assert!(display.is_pixel_on(126, 63));
+        assert!(display.is_pixel_on(127, 63));
+        assert!(!display.is_pixel_on(0, 63), "clipped at the right edge");
+        assert!(!display.is_pixel_on(126, 0), "clipped at the bottom edge");
+    }
+
+    #[test]
+    fn test_drw_reads_past_rom() {
+        // Arrange
+        let mut display = Display::new();
+        let mut memory = Memory::new();
+        let mut register = Register::new();
+        memory.load_rom(&[0xF0, 0x0F]).unwrap();
+        memory.set_byte(0x202, 0xFF);
+        memory.set_byte(0x203, 0x81);
+        register.set_i(0x200);
+        let quirks = Quirks {
+            zero_reads_past_rom: false,
+            ..Quirks::default()
+        };
+        let instruction = DRW::new(0x0, 0x1, 0x4);
+
+        // Act
+        instruction.execute(&mut register, &mut display, &mut memory, quirks);
+
+        // Assert
+        assert_eq!(sprite_rows(&display, 4), vec![0xF0, 0x0F, 0xFF, 0x81]);
+    }
+
+    #[test]
+    fn test_drw_zero_reads_past_rom() {
+        // Arrange
+        let mut display = Display::new();
+        let mut memory = Memory::new();
+        let mut register = Register::new();
+        memory.load_rom(&[0xF0, 0x0F]).unwrap();
+        memory.set_byte(0x202, 0xFF);
+        memory.set_byte(0x203, 0x81);
+        register.set_i(0x200);
+        let quirks = Quirks {
+            zero_reads_past_rom: true,
+            ..Quirks::default()
+        };
+        let instruction = DRW::new(0x0, 0x1, 0x4);
+
+        // Act
+        instruction.execute(&mut register, &mut display, &mut memory, quirks);
+
+        // Assert
+        assert_eq!(sprite_rows(&display, 4), vec![0xF0, 0x0F, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_drw_wrap_sprite_reads() {
+        // Arrange
+        let mut display = Display::new();
+        let mut memory = Memory::new();
+        let mut register = Register::new();
+        for (address, byte) in [
+            (0xFFE, 0xF0),
+            (0xFFF, 0x0F),
+            (0x000, 0xFF),
+            (0x001, 0x81),
+            (0x002, 0x3C),
+        ] {
+            memory.set_byte(address, byte);
+        }
+        register.set_i(0x0FFE);
+        let quirks = Quirks {
+            wrap_sprite_reads: true,
+            ..Quirks::default()
+        };
+        let instruction = DRW::new(0x0, 0x1, 0x5);
+
+        // Act
+        instruction.execute(&mut register, &mut display, &mut memory, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(sprite_rows(&display, 5), vec![0xF0, 0x0F, 0xFF, 0x81, 0x3C]);
+        assert_eq!(register.get_v(0xF), 0x0);
+    }
+
+    #[test]
+    fn test_drw_clamp_sprite_reads() {
+        // Arrange
+        let mut display = Display::new();
+        let mut memory = Memory::new();
+        let mut register = Register::new();
+        for (address, byte) in [
+            (0xFFE, 0xF0),
+            (0xFFF, 0x0F),
+            (0x000, 0xFF),
+            (0x001, 0x81),
+            (0x002, 0x3C),
+        ] {
+            memory.set_byte(address, byte);
+        }
+        register.set_i(0x0FFE);
+        let quirks = Quirks {
+            wrap_sprite_reads: false,
+            ..Quirks::default()
+        };
+        let instruction = DRW::new(0x0, 0x1, 0x5);
+
+        // Act
+        instruction.execute(&mut register, &mut display, &mut memory, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(sprite_rows(&display, 5), vec![0xF0, 0x0F, 0x0F, 0x0F, 0x0F]);
+        assert_eq!(register.get_v(0xF), 0x0);
+    }
+
     #[test]
     fn test_skp_equal() {
         // Arrange
@@ -1564,6 +2736,82 @@ mod tests {
         assert_eq!(register.get_program_counter(), 0x202);
     }
 
+    #[test]
+    fn test_skp_two_keys() {
+        use crate::chip8::keypad::Key;
+
+        // Arrange
+        let mut keypad = Keypad::new();
+        keypad.pressed(Key::Key2);
+        keypad.pressed(Key::Key7);
+
+        for (vx, program_counter) in [(0x2, 0x204), (0x7, 0x204), (0x3, 0x202)] {
+            let mut register = Register::new();
+            register.set_v(0x4, vx);
+
+            let instruction = SKP::new(0x4);
+
+            // Act
+            instruction.execute(&mut register, &keypad);
+
+            // Assert
+            assert_eq!(
+                register.get_program_counter(),
+                program_counter,
+                "V4 = {}",
+                vx
+            );
+        }
+    }
+
+    #[test]
+    fn test_skp_sknp_past_keypad() {
+        use crate::chip8::keypad::Key;
+
+        // Arrange
+        let mut keypad = Keypad::new();
+        keypad.pressed(Key::Key0);
+        let mut skp_register = Register::new();
+        skp_register.set_v(0x4, 0x10);
+        let mut sknp_register = skp_register.clone();
+
+        // Act
+        SKP::new(0x4).execute(&mut skp_register, &keypad);
+        SKNP::new(0x4).execute(&mut sknp_register, &keypad);
+
+        // Assert
+        assert_eq!(skp_register.get_program_counter(), 0x202, "no key 0x10");
+        assert_eq!(sknp_register.get_program_counter(), 0x204);
+    }
+
+    #[test]
+    fn test_sknp_two_keys() {
+        use crate::chip8::keypad::Key;
+
+        // Arrange
+        let mut keypad = Keypad::new();
+        keypad.pressed(Key::Key2);
+        keypad.pressed(Key::Key7);
+
+        for (vx, program_counter) in [(0x2, 0x202), (0x7, 0x202), (0x3, 0x204)] {
+            let mut register = Register::new();
+            register.set_v(0x4, vx);
+
+            let instruction = SKNP::new(0x4);
+
+            // Act
+            instruction.execute(&mut register, &keypad);
+
+            // Assert
+            assert_eq!(
+                register.get_program_counter(),
+                program_counter,
+                "V4 = {}",
+                vx
+            );
+        }
+    }
+
     #[test]
     fn test_sknp_equal() {
         // Arrange
@@ -1636,16 +2884,40 @@ mod tests {
         // Arrange
         let mut register = Register::new();
         let mut keypad = Keypad::new();
+        let mut pending_key = None;
         keypad.pressed(crate::chip8::keypad::Key::Key2);
 
         let instruction = LDK::new(0x4);
 
         // Act
-        instruction.execute(&mut register, &keypad);
+        instruction.execute(&mut register, &keypad, &mut pending_key);
+        instruction.execute(&mut register, &keypad, &mut pending_key);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x200);
+        assert_eq!(register.get_v(0x4), 0x0);
+        assert_eq!(pending_key, Some(0x2));
+    }
+
+    #[test]
+    fn test_ldk_released() {
+        // Arrange
+        let mut register = Register::new();
+        let mut keypad = Keypad::new();
+        let mut pending_key = None;
+        keypad.pressed(crate::chip8::keypad::Key::Key2);
+
+        let instruction = LDK::new(0x4);
+
+        // Act
+        instruction.execute(&mut register, &keypad, &mut pending_key);
+        keypad.released(crate::chip8::keypad::Key::Key2);
+        instruction.execute(&mut register, &keypad, &mut pending_key);
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
         assert_eq!(register.get_v(0x4), 0x2);
+        assert_eq!(pending_key, None);
     }
 
     #[test]
@@ -1653,12 +2925,13 @@ mod tests {
         // Arrange
         let mut register = Register::new();
         let keypad = Keypad::new();
+        let mut pending_key = None;
         register.set_v(0x4, 0x7);
 
         let instruction = LDK::new(0x4);
 
         // Act
-        instruction.execute(&mut register, &keypad);
+        instruction.execute(&mut register, &keypad, &mut pending_key);
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x200);
@@ -1679,7 +2952,7 @@ mod tests {
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
-        assert_eq!(delay_timer.get(), 0x2);
+        assert_eq!(delay_timer.value(), 0x2);
     }
 
     #[test]
@@ -1696,7 +2969,7 @@ mod tests {
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
-        assert_eq!(sound_timer.get(), 0x2);
+        assert_eq!(sound_timer.value(), 0x2);
     }
 
     #[test]
@@ -1709,13 +2982,80 @@ mod tests {
         let instruction = ADDI::new(0x4);
 
         // Act
-        instruction.execute(&mut register);
+        instruction.execute(&mut register, Quirks::default());
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
         assert_eq!(register.get_i(), 0x420);
     }
 
+    #[test]
+    fn test_addi_wraps() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_i(0xFFF0);
+        register.set_v(0x4, 0x20);
+        register.set_v(0xF, 0x7);
+
+        let instruction = ADDI::new(0x4);
+
+        // Act
+        instruction.execute(&mut register, Quirks::default());
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_i(), 0x0010);
+        assert_eq!(register.get_v(0xF), 0x7);
+    }
+
+    #[test]
+    fn test_addi_sets_vf() {
+        let cases = [
+            // i, vx, vf
+            (0x0FF0, 0x0F, 0x0),
+            (0x0FF0, 0x10, 0x1),
+            (0x0FFF, 0xFF, 0x1),
+        ];
+
+        for (i, vx, vf) in cases {
+            // Arrange
+            let mut register = Register::new();
+            register.set_i(i);
+            register.set_v(0x4, vx);
+            register.set_v(0xF, 0x7);
+            let quirks = Quirks {
+                add_to_index_sets_vf: true,
+                ..Quirks::default()
+            };
+
+            let instruction = ADDI::new(0x4);
+
+            // Act
+            instruction.execute(&mut register, quirks);
+
+            // Assert
+            assert_eq!(register.get_i(), i + u16::from(vx));
+            assert_eq!(register.get_v(0xF), vf, "{:03X} + {:02X}", i, vx);
+        }
+    }
+
+    #[test]
+    fn test_ldhf() {
+        for (vx, i) in [(0x0, 0x0A0), (0x1, 0x0AA), (0xF, 0x136)] {
+            // Arrange
+            let mut register = Register::new();
+            register.set_v(0x4, vx);
+            let instruction = LDHF::new(0x4);
+
+            // Act
+            instruction.execute(&mut register);
+
+            // Assert
+            assert_eq!(register.get_program_counter(), 0x202);
+            assert_eq!(register.get_i(), i);
+        }
+    }
+
     #[test]
     fn test_ldf() {
         let mut register = Register::new();
@@ -1761,6 +3101,34 @@ mod tests {
         assert_eq!(memory.get_byte(0x400 + 0x2), 0x6);
     }
 
+    #[test]
+    fn test_ldb_digits() {
+        let cases = [
+            // vx, digits
+            (0, [0, 0, 0]),
+            (9, [0, 0, 9]),
+            (10, [0, 1, 0]),
+            (100, [1, 0, 0]),
+            (255, [2, 5, 5]),
+        ];
+
+        for (vx, digits) in cases {
+            // Arrange
+            let mut register = Register::new();
+            let mut memory = Memory::new();
+            let instruction = LDB::new(0x4);
+            register.set_i(0x400);
+            register.set_v(0x4, vx);
+
+            // Act
+            instruction.execute(&mut register, &mut memory);
+
+            // Assert
+            assert_eq!(memory.get_slice(0x400, 3), digits, "{}", vx);
+            assert_eq!(register.get_i(), 0x400);
+        }
+    }
+
     #[test]
     fn test_ldiv() {
         // Arrange
@@ -1786,10 +3154,11 @@ mod tests {
         register.set_v(0xF, 0xF);
 
         // Act
-        instruction.execute(&mut register, &mut memory);
+        instruction.execute(&mut register, &mut memory, Quirks::default());
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_i(), 0x400);
         assert_eq!(memory.get_byte(0x400), 0x2);
         assert_eq!(memory.get_byte(0x400 + 0x1), 0x4);
         assert_eq!(memory.get_byte(0x400 + 0x2), 0x6);
@@ -1833,10 +3202,11 @@ mod tests {
         memory.set_byte(0x400 + 0xF, 0xF);
 
         // Act
-        instruction.execute(&mut register, &mut memory);
+        instruction.execute(&mut register, &mut memory, Quirks::default());
 
         // Assert
         assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_i(), 0x400);
         assert_eq!(register.get_v(0x0), 0x2);
         assert_eq!(register.get_v(0x1), 0x4);
         assert_eq!(register.get_v(0x2), 0x6);
@@ -1854,4 +3224,161 @@ mod tests {
         assert_eq!(register.get_v(0xE), 0xD);
         assert_eq!(register.get_v(0xF), 0xF);
     }
+
+    #[test]
+    fn test_ldiv_memory_increment() {
+        // Arrange
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        let instruction = LDIV::new(0x2);
+        register.set_i(0x400);
+        register.set_v(0x0, 0x2);
+        register.set_v(0x1, 0x4);
+        register.set_v(0x2, 0x6);
+        let quirks = Quirks {
+            memory_increment: true,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, &mut memory, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_i(), 0x403);
+        assert_eq!(memory.get_slice(0x400, 3), [0x2, 0x4, 0x6]);
+    }
+
+    #[test]
+    fn test_ldb_i_wraps() {
+        // Arrange
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        let instruction = LDB::new(0x4);
+        register.set_i(0xFFFF);
+        register.set_v(0x4, 0xF6);
+
+        // Act
+        instruction.execute(&mut register, &mut memory);
+
+        // Assert
+        assert_eq!(memory.get_byte(0xFFF), 0x2);
+        assert_eq!(memory.get_byte(0x000), 0x4);
+        assert_eq!(memory.get_byte(0x001), 0x6);
+    }
+
+    #[test]
+    fn test_ldiv_i_wraps() {
+        // Arrange
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        let instruction = LDIV::new(0x1);
+        register.set_i(0xFFFF);
+        register.set_v(0x0, 0x2);
+        register.set_v(0x1, 0x4);
+        let quirks = Quirks {
+            memory_increment: true,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, &mut memory, quirks);
+
+        // Assert
+        assert_eq!(memory.get_byte(0xFFF), 0x2);
+        assert_eq!(memory.get_byte(0x000), 0x4);
+        assert_eq!(register.get_i(), 0x0001);
+    }
+
+    #[test]
+    fn test_ldvi_i_wraps() {
+        // Arrange
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        let instruction = LDVI::new(0x1);
+        register.set_i(0xFFFF);
+        memory.set_byte(0xFFF, 0x2);
+        memory.set_byte(0x000, 0x4);
+        let quirks = Quirks {
+            memory_increment: true,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, &mut memory, quirks);
+
+        // Assert
+        assert_eq!(register.get_v(0x0), 0x2);
+        assert_eq!(register.get_v(0x1), 0x4);
+        assert_eq!(register.get_i(), 0x0001);
+    }
+
+    #[test]
+    fn test_ldvi_memory_increment() {
+        // Arrange
+        let mut register = Register::new();
+        let mut memory = Memory::new();
+        let instruction = LDVI::new(0x2);
+        register.set_i(0x400);
+        memory.set_byte(0x400, 0x2);
+        memory.set_byte(0x400 + 0x1, 0x4);
+        memory.set_byte(0x400 + 0x2, 0x6);
+        let quirks = Quirks {
+            memory_increment: true,
+            ..Quirks::default()
+        };
+
+        // Act
+        instruction.execute(&mut register, &mut memory, quirks);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+        assert_eq!(register.get_i(), 0x403);
+        assert_eq!(register.get_v(0x0), 0x2);
+        assert_eq!(register.get_v(0x1), 0x4);
+        assert_eq!(register.get_v(0x2), 0x6);
+    }
+
+    #[test]
+    fn test_ldrv_ldvr_round_trip() {
+        // Arrange
+        let mut register = Register::new();
+        for x in 0x0..=0xF {
+            register.set_v(x, 0x10 + x);
+        }
+
+        // Act
+        LDRV::new(0xF).execute(&mut register);
+        for x in 0x0..=0xF {
+            register.set_v(x, 0x00);
+        }
+        LDVR::new(0xF).execute(&mut register);
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x204);
+        for x in 0x0..=0x7 {
+            assert_eq!(register.get_v(x), 0x10 + x, "V{:X}", x);
+        }
+        for x in 0x8..=0xF {
+            assert_eq!(register.get_v(x), 0x00, "V{:X} is past the flags", x);
+        }
+    }
+
+    #[test]
+    fn test_ldvr_partial() {
+        // Arrange
+        let mut register = Register::new();
+        register.set_v(0x0, 0xAA);
+        register.set_v(0x1, 0xBB);
+        LDRV::new(0x1).execute(&mut register);
+        register.set_v(0x0, 0x00);
+        register.set_v(0x1, 0x00);
+
+        // Act
+        LDVR::new(0x0).execute(&mut register);
+
+        // Assert
+        assert_eq!(register.get_v(0x0), 0xAA);
+        assert_eq!(register.get_v(0x1), 0x00);
+    }
 }
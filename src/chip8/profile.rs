@@ -0,0 +1,10 @@
+/// Time spent decoding versus executing instructions, collected while profiling is enabled
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProfileStats {
+    /// Time spent in `Operation::parse` turning opcodes into operations
+    pub parse: std::time::Duration,
+    /// Time spent executing operations
+    pub execute: std::time::Duration,
+    /// Instructions executed, an average is `execute / instructions`
+    pub instructions: u64,
+}
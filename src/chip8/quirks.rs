@@ -0,0 +1,78 @@
+/// Behaviours which differ between CHIP-8 interpreters
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quirks {
+    /// `Fx1E` sets VF to 1 when I passes 0xFFF and to 0 otherwise, like the Amiga interpreter
+    pub add_to_index_sets_vf: bool,
+    /// Bnnn jumps to nnn plus Vx, x being the highest nibble of nnn, instead of plus V0
+    pub jump_with_vx: bool,
+    /// 8xy6 and 8xyE shift Vx in place, instead of first setting Vx = Vy
+    pub shift_in_place: bool,
+    /// 8xy1, 8xy2 and 8xy3 reset VF to 0 after writing their result
+    pub logic_resets_vf: bool,
+    /// Fx55 and Fx65 leave I pointing past the last register stored or loaded, like the COSMAC VIP
+    pub memory_increment: bool,
+    /// Dxyn waits for the next frame before execution continues, so at most one sprite is drawn a frame
+    pub display_wait: bool,
+    /// Dxyn stops drawing sprites at the edges of the display, instead of wrapping them to the opposite side
+    pub clip_sprites: bool,
+    /// Dxyn reads sprite rows past 0xFFF from 0x000 onwards, instead of repeating the byte at 0xFFF
+    pub wrap_sprite_reads: bool,
+    /// Dxyn reads sprite rows past the end of the loaded ROM as 0, like interpreters which zero-pad ROMs
+    pub zero_reads_past_rom: bool,
+    /// SUPER-CHIP's instructions are available, otherwise they are illegal like on the COSMAC VIP
+    pub superchip_instructions: bool,
+}
+
+impl Default for Quirks {
+    fn default() -> Quirks {
+        Quirks {
+            add_to_index_sets_vf: false,
+            jump_with_vx: false,
+            shift_in_place: true,
+            logic_resets_vf: false,
+            memory_increment: false,
+            display_wait: false,
+            clip_sprites: true,
+            wrap_sprite_reads: true,
+            zero_reads_past_rom: false,
+            superchip_instructions: true,
+        }
+    }
+}
+
+impl Quirks {
+    /// The original interpreter on the COSMAC VIP
+    #[must_use]
+    pub fn cosmac() -> Quirks {
+        Quirks {
+            add_to_index_sets_vf: false,
+            jump_with_vx: false,
+            shift_in_place: false,
+            logic_resets_vf: true,
+            memory_increment: true,
+            display_wait: true,
+            clip_sprites: true,
+            wrap_sprite_reads: true,
+            zero_reads_past_rom: false,
+            superchip_instructions: false,
+        }
+    }
+
+    /// SUPER-CHIP 1.1 on the HP 48
+    #[must_use]
+    pub fn superchip() -> Quirks {
+        Quirks {
+            add_to_index_sets_vf: false,
+            jump_with_vx: true,
+            shift_in_place: true,
+            logic_resets_vf: false,
+            memory_increment: false,
+            display_wait: false,
+            clip_sprites: true,
+            wrap_sprite_reads: true,
+            zero_reads_past_rom: false,
+            superchip_instructions: true,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+/// Hundredths of a second, the unit of a GIF frame's delay
+const CENTISECOND: u128 = 10_000_000;
+
+/// Display frame held for the time until the display next changed
+struct Frame {
+    high_res: bool,
+    pixels: Vec<u8>,
+    /// Nanoseconds since the recording started that the frame was first shown
+    shown_at: u128,
+}
+
+/// Records display frames to an animated GIF, for clips of gameplay in bug reports
+///
+/// Consecutive identical frames are merged into one shown for longer, so a recording of a still
+/// screen stays small. A recording that switches to the 128 x 64 display is that size throughout,
+/// with the 64 x 32 frames scaled up.
+#[derive(Default)]
+pub struct GifRecorder {
+    frames: Vec<Frame>,
+    /// Nanoseconds recorded so far
+    elapsed: u128,
+}
+
+impl GifRecorder {
+    #[must_use]
+    pub fn new() -> GifRecorder {
+        GifRecorder::default()
+    }
+
+    /// Appends the display as shown for `delta`, `width` x `height` pixels from its top left
+    pub fn push(
+        &mut self,
+        buffer: &[[bool; 128]; 64],
+        (width, height): (u8, u8),
+        delta: std::time::Duration,
+    ) {
+        let pixels: Vec<u8> = buffer[..usize::from(height)]
+            .iter()
+            .flat_map(|row| row[..usize::from(width)].iter().map(|&on| u8::from(on)))
+            .collect();
+        let high_res = width > 64;
+
+        let changed = match self.frames.last() {
+            Some(last) => last.high_res != high_res || last.pixels != pixels,
+            None => true,
+        };
+        if changed {
+            self.frames.push(Frame {
+                high_res,
+                pixels,
+                shown_at: self.elapsed,
+            });
+        }
+
+        self.elapsed += delta.as_nanos();
+    }
+
+    /// Frames recorded so far, counting identical consecutive frames once
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Encodes the recording as a looping GIF of white pixels on black
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the GIF can't be encoded.
+    pub fn finish(self) -> crate::Result<Vec<u8>> {
+        let high_res = self.frames.iter().any(|frame| frame.high_res);
+        let (width, height) = if high_res { (128, 64) } else { (64, 32) };
+
+        let mut encoder = gif::Encoder::new(
+            Vec::new(),
+            width,
+            height,
+            &[0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF],
+        )?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        for (index, frame) in self.frames.iter().enumerate() {
+            let hidden_at = self
+                .frames
+                .get(index + 1)
+                .map_or(self.elapsed, |next| next.shown_at);
+
+            let pixels = if high_res && !frame.high_res {
+                scale_up(&frame.pixels)
+            } else {
+                frame.pixels.clone()
+            };
+
+            let mut gif_frame = gif::Frame::from_indexed_pixels(width, height, &pixels, None);
+            // rounds each frame's start rather than its length, so the delays don't drift
+            gif_frame.delay = u16::try_from(
+                (hidden_at + CENTISECOND / 2) / CENTISECOND
+                    - (frame.shown_at + CENTISECOND / 2) / CENTISECOND,
+            )
+            .unwrap_or(u16::MAX);
+            encoder.write_frame(&gif_frame)?;
+        }
+
+        Ok(encoder.into_inner()?)
+    }
+}
+
+/// 64 x 32 pixels as 128 x 64, each pixel doubled in both directions
+fn scale_up(pixels: &[u8]) -> Vec<u8> {
+    (0..64)
+        .flat_map(|y| (0..128).map(move |x| pixels[y / 2 * 64 + x / 2]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(gif: &[u8]) -> (u16, u16, Vec<u16>) {
+        let mut decoder = gif::DecodeOptions::new().read_info(gif).unwrap();
+        let (width, height) = (decoder.width(), decoder.height());
+        let mut delays = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            delays.push(frame.delay);
+        }
+        (width, height, delays)
+    }
+
+    #[test]
+    fn test_finish_blinking_pixel() {
+        // Arrange
+        let mut recorder = GifRecorder::new();
+        let mut buffer = [[false; 128]; 64];
+        let frame = std::time::Duration::from_secs(1) / 60;
+
+        // Act
+        for n in 0..6 {
+            buffer[0][0] = n % 2 == 0;
+            recorder.push(&buffer, (64, 32), frame);
+        }
+        let gif = recorder.finish().unwrap();
+
+        // Assert
+        let (width, height, delays) = decode(&gif);
+        assert_eq!((width, height), (64, 32));
+        assert_eq!(delays.len(), 6);
+        assert_eq!(
+            delays.iter().map(|&delay| u32::from(delay)).sum::<u32>(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_finish_merges_identical_frames() {
+        // Arrange
+        let mut recorder = GifRecorder::new();
+        let buffer = [[false; 128]; 64];
+
+        // Act
+        for _ in 0..3 {
+            recorder.push(&buffer, (64, 32), std::time::Duration::from_millis(100));
+        }
+        let gif = recorder.finish().unwrap();
+
+        // Assert
+        assert_eq!(decode(&gif).2, vec![30]);
+    }
+
+    #[test]
+    fn test_finish_high_res() {
+        // Arrange
+        let mut recorder = GifRecorder::new();
+        let mut buffer = [[false; 128]; 64];
+        buffer[31][63] = true;
+        recorder.push(&buffer, (64, 32), std::time::Duration::from_millis(100));
+
+        // Act
+        recorder.push(&buffer, (128, 64), std::time::Duration::from_millis(100));
+        let gif = recorder.finish().unwrap();
+
+        // Assert
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(gif.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (128, 64));
+        let first = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!(
+            first.buffer[63 * 128 + 127],
+            1,
+            "scaled up bottom right pixel"
+        );
+        assert_eq!(first.buffer[62 * 128 + 126], 1);
+        let second = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!(second.buffer[63 * 128 + 127], 0);
+        assert_eq!(second.buffer[31 * 128 + 63], 1);
+    }
+}
@@ -1,8 +1,12 @@
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(super) struct Register {
     /// 16-bit index register called "I" which is used to point at locations in memory
     i: u16,
     /// A program counter, often called just "PC", which points to the current instruction in memory
     program_counter: u16,
+    /// SUPER-CHIP's 8 RPL user flags, which FX75 and FX85 save V0..V7 to and restore them from
+    rpl: [u8; 8],
     /// A stack for 16-bit addresses which is used to call subroutines/function and return from them
     stack: Vec<u16>,
     /// 16 8-bit general purpose variable registers numbered `0` through `F`, called `V0` through `VF`
@@ -14,6 +18,7 @@ impl Register {
         Register {
             i: 0,
             program_counter: 0x200,
+            rpl: [0; 8],
             stack: Vec::new(),
             v: [0; 16],
         }
@@ -35,14 +40,28 @@ impl Register {
         self.program_counter = program_counter;
     }
 
+    /// Addresses are 12 bits, a program running off the end of memory continues from 0x000
     pub(super) fn increment_program_counter(&mut self) {
-        self.program_counter += 2;
+        self.program_counter = self.program_counter.wrapping_add(2) & 0x0FFF;
+    }
+
+    pub(super) fn get_rpl(&self, x: u8) -> u8 {
+        self.rpl[x as usize]
+    }
+
+    pub(super) fn set_rpl(&mut self, x: u8, nn: u8) {
+        self.rpl[x as usize] = nn;
     }
 
     pub(super) fn push_stack(&mut self, nnn: u16) {
         self.stack.push(nnn);
     }
 
+    /// Return addresses on the stack
+    pub(super) fn stack_depth(&self) -> usize {
+        self.stack.len()
+    }
+
     pub(super) fn pop_stack(&mut self) -> u16 {
         self.stack.pop().expect("failed to pop stack")
     }
@@ -55,8 +74,50 @@ impl Register {
         self.v[x as usize] = nn;
     }
 
-    #[cfg(feature = "editor")]
+    /// V0 through VF
+    pub(super) fn v_registers(&self) -> [u8; 16] {
+        self.v
+    }
+
+    /// Overwrites V0 through VF at once, for restoring save states
+    pub(super) fn set_v_registers(&mut self, v: [u8; 16]) {
+        self.v = v;
+    }
+
+    #[cfg(any(test, feature = "editor"))]
     pub(super) fn get_stack(&self) -> &[u16] {
         &self.stack
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_increment_program_counter() {
+        // Arrange
+        let mut register = Register::new();
+
+        // Act
+        register.increment_program_counter();
+
+        // Assert
+        assert_eq!(register.get_program_counter(), 0x202);
+    }
+
+    #[test]
+    fn test_increment_program_counter_wraps() {
+        for (program_counter, incremented) in [(0xFFE, 0x000), (0xFFFE, 0x000), (0xFFFF, 0x001)] {
+            // Arrange
+            let mut register = Register::new();
+            register.set_program_counter(program_counter);
+
+            // Act
+            register.increment_program_counter();
+
+            // Assert
+            assert_eq!(register.get_program_counter(), incremented);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use image::{codecs::png::PngEncoder, ColorType, Rgb, RgbImage};
+
+/// PNG of the top left `width` x `height` pixels of the buffer, each drawn as a `scale` x `scale` square
+pub(super) fn encode_png(
+    buffer: &[[bool; 128]; 64],
+    (width, height): (u8, u8),
+    scale: u32,
+    on: [u8; 3],
+    off: [u8; 3],
+) -> crate::Result<Vec<u8>> {
+    let scale = scale.max(1);
+
+    let image = RgbImage::from_fn(
+        u32::from(width) * scale,
+        u32::from(height) * scale,
+        |x, y| {
+            if buffer[(y / scale) as usize][(x / scale) as usize] {
+                Rgb(on)
+            } else {
+                Rgb(off)
+            }
+        },
+    );
+
+    let mut png = Vec::new();
+    PngEncoder::new(&mut png).encode(&image, image.width(), image.height(), ColorType::Rgb8)?;
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_png() {
+        // Arrange
+        let mut buffer = [[false; 128]; 64];
+        buffer[0][0] = true;
+        buffer[31][63] = true;
+        buffer[1][64] = true;
+
+        // Act
+        let png = encode_png(&buffer, (64, 32), 2, [0xFF, 0x00, 0x00], [0x00; 3]).unwrap();
+
+        // Assert
+        let image = image::load_from_memory(&png).unwrap().into_rgb8();
+        assert_eq!(image.dimensions(), (128, 64));
+        assert_eq!(image.get_pixel(0, 0), &Rgb([0xFF, 0x00, 0x00]));
+        assert_eq!(image.get_pixel(1, 1), &Rgb([0xFF, 0x00, 0x00]));
+        assert_eq!(image.get_pixel(2, 0), &Rgb([0x00; 3]));
+        assert_eq!(image.get_pixel(127, 63), &Rgb([0xFF, 0x00, 0x00]));
+        assert_eq!(image.get_pixel(126, 61), &Rgb([0x00; 3]));
+    }
+}
@@ -0,0 +1,26 @@
+use super::{emulator::EmulatorStatus, register::Register, timer::Timer};
+
+/// Snapshot of a running program, restored with [`Emulator::load_state`](super::emulator::Emulator::load_state)
+///
+/// Covers what the program can observe and the time it was emulated for, the emulator's
+/// configuration such as its quirks and breakpoints is left as it is when a state is loaded.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmulatorState {
+    pub(super) cycles: u64,
+    pub(super) delay_timer: Timer,
+    /// Rows of pixels from the top, the most significant bit is the leftmost pixel
+    pub(super) display: Vec<u128>,
+    pub(super) high_res: bool,
+    /// Key pressed while `Fx0A` waits for it to be released
+    pub(super) pending_key: Option<u8>,
+    pub(super) ram: Vec<u8>,
+    pub(super) register: Register,
+    /// V0 through VF
+    pub(super) register_v: [u8; 16],
+    pub(super) rom_end: u16,
+    pub(super) sound_timer: Timer,
+    pub(super) status: EmulatorStatus,
+    pub(super) time: std::time::Duration,
+    pub(super) vip_credit: i128,
+}
@@ -1,31 +1,211 @@
+/// Nanoseconds in a second, a 60 Hz tick elapses for every multiple of this in `elapsed`
+const TICK: u128 = 1_000_000_000;
+
+/// Shortfall in `elapsed` a tick still elapses with, a 60th of a second is a fraction of a
+/// nanosecond longer than a frame of [`Frames`] can be
+const TOLERANCE: u128 = 59;
+
+/// Durations of consecutive frames at a fixed rate, whole nanoseconds which carry the fraction
+/// each frame is rounded down by into the next, so a second of frames is exactly a second
+#[derive(Debug, Clone)]
+pub(super) struct Frames {
+    rate: u32,
+    /// Nanoseconds multiplied by the rate not yet given to a frame
+    carry: u128,
+}
+
+impl Frames {
+    pub(super) fn new(rate: u32) -> Frames {
+        Frames {
+            rate: rate.max(1),
+            carry: 0,
+        }
+    }
+
+    pub(super) fn next_frame(&mut self) -> std::time::Duration {
+        let rate = u128::from(self.rate);
+        self.carry += 1_000_000_000;
+        let nanos = self.carry / rate;
+        self.carry %= rate;
+
+        std::time::Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(super) struct Timer {
-    remaining: std::time::Duration,
+    /// Remaining 60 Hz ticks
+    remaining: u8,
+    /// Nanoseconds since the last tick multiplied by 60, so a tick is exactly one second of them
+    elapsed: u128,
 }
 
 impl Timer {
     pub(super) fn new() -> Timer {
         Timer {
-            remaining: std::time::Duration::ZERO,
+            remaining: 0,
+            elapsed: 0,
         }
     }
 
-    #[allow(clippy::cast_possible_truncation)]
-    pub(super) fn get(&self) -> u8 {
-        let remaining = self.remaining.as_millis() * 60 / 1000;
-        let remainder = self.remaining.as_millis() % 1_000;
-
-        if remainder == 0 {
-            remaining as u8
-        } else {
-            remaining as u8 + 1
-        }
+    pub(super) fn value(&self) -> u8 {
+        self.remaining
     }
 
     pub(super) fn set(&mut self, nn: u8) {
-        self.remaining = std::time::Duration::from_secs(u64::from(nn)) / 60;
+        self.remaining = nn;
+        self.elapsed = 0;
     }
 
     pub(super) fn tick(&mut self, duration: &std::time::Duration) {
-        self.remaining = self.remaining.saturating_sub(*duration);
+        if self.remaining == 0 {
+            return;
+        }
+
+        self.elapsed += duration.as_nanos() * 60;
+
+        while self.remaining > 0 && self.elapsed + TOLERANCE >= TICK {
+            self.remaining -= 1;
+            self.elapsed = self.elapsed.saturating_sub(TICK);
+        }
+
+        if self.remaining == 0 {
+            self.elapsed = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        // Arrange
+        let mut timer = Timer::new();
+        let mut frames = Frames::new(60);
+
+        // Act
+        timer.set(2);
+
+        // Assert
+        assert_eq!(timer.value(), 2);
+
+        // Act
+        timer.tick(&frames.next_frame());
+
+        // Assert
+        assert_eq!(timer.value(), 1);
+
+        // Act
+        timer.tick(&frames.next_frame());
+
+        // Assert
+        assert_eq!(timer.value(), 0);
+    }
+
+    #[test]
+    fn test_tick_sub_millisecond() {
+        // Arrange
+        let mut timer = Timer::new();
+        timer.set(2);
+
+        // Act
+        for _ in 0..100 {
+            timer.tick(&std::time::Duration::from_micros(100));
+        }
+
+        // Assert
+        assert_eq!(timer.value(), 2);
+
+        // Act
+        for _ in 0..67 {
+            timer.tick(&std::time::Duration::from_micros(100));
+        }
+
+        // Assert
+        assert_eq!(timer.value(), 1);
+    }
+
+    #[test]
+    fn test_tick_one_second() {
+        // Arrange
+        let mut timer = Timer::new();
+        timer.set(255);
+
+        // Act
+        for _ in 0..10_000 {
+            timer.tick(&std::time::Duration::from_micros(100));
+        }
+
+        // Assert
+        assert_eq!(timer.value(), 255 - 60);
+    }
+
+    #[test]
+    fn test_tick_high_refresh_rate() {
+        // Arrange
+        let mut timer = Timer::new();
+        timer.set(60);
+
+        let mut frames = Frames::new(144);
+
+        // Act
+        for _ in 0..143 {
+            timer.tick(&frames.next_frame());
+        }
+
+        // Assert
+        assert_eq!(timer.value(), 1);
+
+        // Act
+        timer.tick(&frames.next_frame());
+
+        // Assert
+        assert_eq!(timer.value(), 0, "60 ticks in a second");
+    }
+
+    #[test]
+    fn test_tick_60_frames() {
+        // Arrange
+        let mut timer = Timer::new();
+        timer.set(61);
+        let mut frames = Frames::new(60);
+
+        // Act
+        for _ in 0..60 {
+            timer.tick(&frames.next_frame());
+        }
+
+        // Assert
+        assert_eq!(timer.value(), 1, "a tick a frame");
+    }
+
+    #[test]
+    fn test_frames() {
+        // Arrange
+        let mut frames = Frames::new(60);
+
+        // Act
+        let second: std::time::Duration = (0..60).map(|_| frames.next_frame()).sum();
+
+        // Assert
+        assert_eq!(second, std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_tick_expired() {
+        // Arrange
+        let mut timer = Timer::new();
+        timer.set(1);
+
+        // Act
+        timer.tick(&std::time::Duration::from_secs(1));
+        timer.set(1);
+        timer.tick(&std::time::Duration::from_millis(16));
+
+        // Assert
+        assert_eq!(timer.value(), 1);
     }
 }
@@ -0,0 +1,25 @@
+/// Reason a loaded ROM might misbehave with the emulator's configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Warning {
+    /// SUPER-CHIP instruction at the address, while the quirks leave SUPER-CHIP's instructions out
+    SuperChipInstruction { address: u16 },
+    /// Dxy0 at the address draws a 16 x 16 sprite, but nothing in the ROM switches to the 128 x 64 display
+    HighResWithoutSwitch { address: u16 },
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::SuperChipInstruction { address } => write!(
+                f,
+                "instruction at {:03X} is SUPER-CHIP only, which the quirks do not enable",
+                address
+            ),
+            Warning::HighResWithoutSwitch { address } => write!(
+                f,
+                "instruction at {:03X} needs the 128 x 64 display, which the ROM never switches to",
+                address
+            ),
+        }
+    }
+}
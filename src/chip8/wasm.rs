@@ -0,0 +1,211 @@
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use super::{emulator::Emulator, keypad::Keypad};
+
+/// Milliseconds emulated by each fixed step of [`WasmChip8::update`], a 60 Hz display frame
+const STEP_MS: f64 = 1000.0 / 60.0;
+
+/// Steps a single update emulates at most, so a tab left in the background doesn't stall on return
+const MAX_STEPS: u32 = 10;
+
+/// CHIP-8 interpreter for browser frontends, exported to JavaScript with the `wasm` feature
+///
+/// Takes time in milliseconds as given by `requestAnimationFrame` and emulates it in fixed 60 Hz
+/// steps, so the same elapsed time runs the same instructions however the browser schedules frames.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct WasmChip8 {
+    emulator: Emulator,
+    /// Milliseconds given to updates which haven't been emulated yet
+    pending_ms: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl WasmChip8 {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    #[must_use]
+    pub fn new() -> WasmChip8 {
+        WasmChip8 {
+            emulator: Emulator::builder().build(),
+            pending_ms: 0.0,
+        }
+    }
+
+    /// Loads the ROM at 0x200 and starts running it
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` with the reason, thrown as a string in JavaScript, if the ROM does not fit in memory.
+    pub fn load_rom(&mut self, rom: &[u8]) -> Result<(), String> {
+        self.pending_ms = 0.0;
+        self.emulator
+            .load_rom(rom)
+            .map_err(|error| error.to_string())
+    }
+
+    /// Emulates the milliseconds passing since the last update
+    pub fn update(&mut self, dt_ms: f64) {
+        if dt_ms.is_finite() && dt_ms > 0.0 {
+            self.pending_ms += dt_ms;
+        }
+
+        let mut steps = 0;
+        while self.pending_ms >= STEP_MS && steps < MAX_STEPS {
+            self.emulator.tick_frames(1);
+            self.pending_ms -= STEP_MS;
+            steps += 1;
+        }
+
+        if steps == MAX_STEPS {
+            self.pending_ms = self.pending_ms.min(STEP_MS);
+        }
+    }
+
+    /// Presses the hex key, keys above 0xF are ignored
+    pub fn key_down(&mut self, key: u8) {
+        if let Some(key) = Keypad::key(key) {
+            self.emulator.key_pressed(key);
+        }
+    }
+
+    /// Releases the hex key, keys above 0xF are ignored
+    pub fn key_up(&mut self, key: u8) {
+        if let Some(key) = Keypad::key(key) {
+            self.emulator.key_released(key);
+        }
+    }
+
+    /// Display as rows of pixels from the top, a byte per pixel that is 1 when on and 0 when off
+    ///
+    /// [`WasmChip8::width`] x [`WasmChip8::height`] bytes long, which change when the program
+    /// switches between 64 x 32 and 128 x 64.
+    #[must_use]
+    pub fn framebuffer(&self) -> Vec<u8> {
+        let (width, height) = self.emulator.framebuffer_size();
+
+        self.emulator.framebuffer()[..usize::from(height)]
+            .iter()
+            .flat_map(|row| row[..usize::from(width)].iter().map(|&on| u8::from(on)))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn width(&self) -> u8 {
+        self.emulator.framebuffer_size().0
+    }
+
+    #[must_use]
+    pub fn height(&self) -> u8 {
+        self.emulator.framebuffer_size().1
+    }
+}
+
+impl Default for WasmChip8 {
+    fn default() -> WasmChip8 {
+        WasmChip8::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::assembler, *};
+
+    #[test]
+    fn test_update() {
+        // Arrange
+        let rom = std::fs::read("./roms/IBM Logo.ch8").unwrap();
+        let mut chip8 = WasmChip8::new();
+        chip8.load_rom(&rom).unwrap();
+
+        // Act
+        for _ in 0..60 {
+            chip8.update(STEP_MS);
+        }
+
+        // Assert
+        let framebuffer = chip8.framebuffer();
+        assert_eq!((chip8.width(), chip8.height()), (64, 32));
+        assert_eq!(framebuffer.len(), 64 * 32);
+        assert_eq!(framebuffer[8 * 64 + 12], 1, "top left corner of the \"I\"");
+        assert_eq!(framebuffer[0], 0);
+    }
+
+    #[test]
+    fn test_update_fixed_step() {
+        // Arrange
+        let rom = [assembler::add1(0x0, 0x01), assembler::jp(0x200)].concat();
+        let mut chip8 = WasmChip8::new();
+        chip8.load_rom(&rom).unwrap();
+
+        // Act
+        chip8.update(STEP_MS / 2.0);
+
+        // Assert
+        assert_eq!(chip8.emulator.cycles(), 0, "less than a step is held back");
+
+        // Act
+        chip8.update(STEP_MS / 2.0);
+        chip8.update(f64::NAN);
+        chip8.update(-1.0);
+
+        // Assert
+        assert!(chip8.emulator.cycles() > 0);
+    }
+
+    #[test]
+    fn test_update_caps_steps() {
+        // Arrange
+        let rom = [assembler::add1(0x0, 0x01), assembler::jp(0x200)].concat();
+        let mut capped = WasmChip8::new();
+        capped.load_rom(&rom).unwrap();
+        let mut stepped = WasmChip8::new();
+        stepped.load_rom(&rom).unwrap();
+
+        // Act
+        capped.update(60_000.0);
+        for _ in 0..MAX_STEPS {
+            stepped.update(STEP_MS);
+        }
+
+        // Assert
+        assert_eq!(capped.emulator.cycles(), stepped.emulator.cycles());
+    }
+
+    #[test]
+    fn test_key_down_key_up() {
+        // Arrange
+        let rom = [
+            assembler::ldk(0x0),
+            assembler::se1(0x0, 0xA),
+            assembler::jp(0x204),
+            assembler::ldi(0x050),
+            assembler::drw(0x1, 0x1, 0x5),
+            assembler::jp(0x20A),
+        ]
+        .concat();
+        let mut chip8 = WasmChip8::new();
+        chip8.load_rom(&rom).unwrap();
+
+        // Act
+        chip8.key_down(0x10);
+        chip8.key_down(0xA);
+        chip8.update(STEP_MS);
+        chip8.key_up(0xA);
+        chip8.update(STEP_MS);
+
+        // Assert
+        assert_eq!(chip8.framebuffer()[0], 1, "drawn once 0xA was read");
+    }
+
+    #[test]
+    fn test_load_rom_too_large() {
+        // Arrange
+        let mut chip8 = WasmChip8::new();
+
+        // Act
+        let result = chip8.load_rom(&[0x00; 0x1000]);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,45 @@
+/// Memory accesses a watchpoint pauses the emulator on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchKind {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+impl WatchKind {
+    /// Whether a watchpoint of this kind pauses on the access, which is either a read or a write
+    pub(super) fn matches(self, access: WatchKind) -> bool {
+        matches!(
+            (self, access),
+            (WatchKind::ReadWrite, _)
+                | (WatchKind::Read, WatchKind::Read)
+                | (WatchKind::Write, WatchKind::Write)
+        )
+    }
+}
+
+/// Watched memory access that paused the emulator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchHit {
+    /// Watched address that was accessed
+    pub address: u16,
+    /// Either [`WatchKind::Read`] or [`WatchKind::Write`]
+    pub access: WatchKind,
+    /// Address of the instruction that accessed it
+    pub program_counter: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        assert!(WatchKind::Read.matches(WatchKind::Read));
+        assert!(!WatchKind::Read.matches(WatchKind::Write));
+        assert!(!WatchKind::Write.matches(WatchKind::Read));
+        assert!(WatchKind::Write.matches(WatchKind::Write));
+        assert!(WatchKind::ReadWrite.matches(WatchKind::Read));
+        assert!(WatchKind::ReadWrite.matches(WatchKind::Write));
+    }
+}
@@ -1,13 +1,131 @@
 pub(crate) mod component {
     use bevy::prelude::*;
 
+    /// Parent of every [`Pixel`] of an emulator, positioned so the displays are centred in the window
+    #[derive(Component)]
+    pub(crate) struct Display {
+        /// Index of the emulator in [`Emulators`](crate::emulator::resource::Emulators)
+        pub(crate) emulator: usize,
+    }
+
+    /// A sprite of the 128 x 64 high resolution grid, low resolution pixels cover 2 x 2 of them
     #[derive(Component)]
     pub(crate) struct Pixel {
+        /// Index of the emulator in [`Emulators`](crate::emulator::resource::Emulators)
+        pub(crate) emulator: usize,
         pub(crate) x: u8,
         pub(crate) y: u8,
     }
 }
 
+pub(crate) mod resource {
+    use bevy::prelude::Color;
+
+    /// Integer scale factor of the display, keeps every CHIP-8 pixel aligned to whole screen pixels
+    pub(crate) struct Scale(pub(crate) u32);
+
+    impl Default for Scale {
+        fn default() -> Scale {
+            Scale(10)
+        }
+    }
+
+    /// Colors of on and off pixels, alpha below 1 lets whatever is behind the display show through
+    pub(crate) struct PixelColors {
+        /// Color of on pixels outside the palette's regions, its alpha also applies to palette colors
+        pub(crate) on: Color,
+        pub(crate) off: Color,
+    }
+
+    impl Default for PixelColors {
+        fn default() -> PixelColors {
+            PixelColors {
+                on: Color::WHITE,
+                off: Color::BLACK,
+            }
+        }
+    }
+
+    impl PixelColors {
+        /// Transparent off pixels, for compositing the display over a background
+        pub(crate) fn overlay(on: Color) -> PixelColors {
+            PixelColors {
+                on,
+                off: Color::NONE,
+            }
+        }
+    }
+}
+
+pub(crate) mod layout {
+    use bevy::prelude::*;
+
+    pub(crate) const PIXELS_X: u8 = 64;
+    pub(crate) const PIXELS_Y: u8 = 32;
+    /// Sprites along each side of a CHIP-8 pixel, enough for SUPER-CHIP's 128 x 64 mode
+    pub(crate) const SUBPIXELS: u8 = 2;
+
+    /// CHIP-8 pixel a sprite of the high resolution grid shows in the active mode
+    pub(crate) fn chip8_pixel(x: u8, y: u8, high_res: bool) -> (u8, u8) {
+        if high_res {
+            (x, y)
+        } else {
+            (x / SUBPIXELS, y / SUBPIXELS)
+        }
+    }
+
+    /// Size of a single sprite of the high resolution grid in screen pixels
+    pub(crate) fn sprite_size(pixel_size: f32) -> f32 {
+        pixel_size / f32::from(SUBPIXELS)
+    }
+
+    /// Size of a single CHIP-8 pixel in screen pixels
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn pixel_size(scale: u32) -> f32 {
+        scale as f32
+    }
+
+    /// Size of the whole CHIP-8 display in screen pixels
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn display_size(scale: u32) -> Vec2 {
+        Vec2::new(
+            (u32::from(PIXELS_X) * scale) as f32,
+            (u32::from(PIXELS_Y) * scale) as f32,
+        )
+    }
+
+    /// Size of a window fitting the displays side by side in screen pixels
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn window_size(scale: u32, displays: usize) -> Vec2 {
+        display_size(scale) * Vec2::new(displays as f32, 1.0)
+    }
+
+    /// Displays fitted side by side inside a window, keeping each at its 2:1 aspect ratio
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct Letterbox {
+        /// Size of a single CHIP-8 pixel in screen pixels
+        pub(crate) pixel_size: f32,
+        /// Size of a whole CHIP-8 display in screen pixels
+        pub(crate) display_size: Vec2,
+        /// Empty space left of and above the displays, the same space is left on the opposite sides
+        pub(crate) margin: Vec2,
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn letterbox(window_size: Vec2, displays: usize) -> Letterbox {
+        let pixels = Vec2::new(f32::from(PIXELS_X), f32::from(PIXELS_Y));
+        let columns = Vec2::new(displays as f32, 1.0);
+        let pixel_size = (window_size / (pixels * columns)).min_element();
+        let display_size = pixels * pixel_size;
+
+        Letterbox {
+            pixel_size,
+            display_size,
+            margin: (window_size - display_size * columns) / 2.0,
+        }
+    }
+}
+
 pub(crate) mod plugin {
     use super::system;
 
@@ -16,106 +134,281 @@ pub(crate) mod plugin {
     impl bevy::prelude::Plugin for Plugin {
         fn build(&self, app: &mut bevy::prelude::App) {
             app.add_startup_system(system::spawn_pixels)
+                .add_system(system::letterbox_pixels)
                 .add_system(system::recolor_pixels);
         }
     }
 }
 
 mod system {
-    use bevy::prelude::*;
+    use bevy::{
+        prelude::*,
+        window::{WindowId, WindowResized},
+    };
 
-    use super::component::Pixel;
-
-    pub(super) fn spawn_pixels(mut commands: Commands) {
-        fn transform(
-            pixel_x: u8,
-            pixel_y: u8,
-            pixel_size: Vec2,
-            pixel_padding_size: f32,
-        ) -> Transform {
-            Transform::from_xyz(
-                f32::from(pixel_x) * (pixel_size.x + pixel_padding_size) + pixel_padding_size / 2.0,
-                f32::from(pixel_y) * (pixel_size.y + pixel_padding_size) + pixel_padding_size / 2.0,
-                0.0,
-            )
-        }
+    use super::{
+        component::{Display, Pixel},
+        layout,
+        resource::{PixelColors, Scale},
+    };
+    use crate::{emulator::resource::Emulators, palette::Palette};
 
-        let display_size = Vec2::new(1280.0, 640.0);
-        let pixels_x: u8 = 64;
-        let pixels_y: u8 = 32;
-        let pixel_padding_size: f32 = 4.0;
+    #[allow(clippy::cast_precision_loss)]
+    fn display_transform(
+        letterbox: &layout::Letterbox,
+        displays: usize,
+        index: usize,
+    ) -> Transform {
+        let sprite_size = layout::sprite_size(letterbox.pixel_size);
 
-        let pixel_size = Vec2::new(
-            display_size.x / f32::from(pixels_x) - pixel_padding_size,
-            display_size.y / f32::from(pixels_y) - pixel_padding_size,
-        );
+        Transform::from_xyz(
+            (-letterbox.display_size.x * displays as f32 + sprite_size) / 2.0
+                + letterbox.display_size.x * index as f32,
+            (-letterbox.display_size.y + sprite_size) / 2.0,
+            0.0,
+        )
+    }
+
+    fn pixel_transform(pixel_x: u8, pixel_y: u8, pixel_size: f32) -> Transform {
+        Transform::from_xyz(
+            f32::from(pixel_x) * pixel_size,
+            f32::from(pixel_y) * pixel_size,
+            0.0,
+        )
+    }
 
-        commands
-            .spawn()
-            .insert_bundle(TransformBundle::from_transform(Transform::from_xyz(
-                -display_size.x / 2.0 + pixel_size.x - pixel_padding_size * 2.0,
-                -display_size.y / 2.0 + pixel_size.y - pixel_padding_size * 2.0,
-                0.0,
-            )))
-            .insert(Name::new("display"))
-            .with_children(|display| {
-                for pixel_y in 0..pixels_y {
-                    for pixel_x in 0..pixels_x {
-                        display
-                            .spawn_bundle(SpriteBundle {
-                                sprite: Sprite {
-                                    color: Color::Rgba {
-                                        red: 255.0,
-                                        green: 255.0,
-                                        blue: 255.0,
-                                        alpha: 1.0,
+    #[allow(clippy::needless_pass_by_value)]
+    pub(super) fn spawn_pixels(
+        mut commands: Commands,
+        scale: Res<Scale>,
+        emulators: Res<Emulators>,
+    ) {
+        let displays = emulators.len();
+        let letterbox = layout::letterbox(layout::window_size(scale.0, displays), displays);
+        let sprite_size = layout::sprite_size(letterbox.pixel_size);
+        let rows = layout::PIXELS_Y * layout::SUBPIXELS;
+        let columns = layout::PIXELS_X * layout::SUBPIXELS;
+
+        for emulator in 0..displays {
+            commands
+                .spawn()
+                .insert_bundle(TransformBundle::from_transform(display_transform(
+                    &letterbox, displays, emulator,
+                )))
+                .insert(Name::new(format!("display {}", emulator)))
+                .insert(Display { emulator })
+                .with_children(|display| {
+                    for pixel_y in 0..rows {
+                        for pixel_x in 0..columns {
+                            display
+                                .spawn_bundle(SpriteBundle {
+                                    sprite: Sprite {
+                                        color: Color::Rgba {
+                                            red: 255.0,
+                                            green: 255.0,
+                                            blue: 255.0,
+                                            alpha: 1.0,
+                                        },
+                                        custom_size: Some(Vec2::splat(sprite_size)),
+                                        ..Default::default()
                                     },
-                                    custom_size: Some(pixel_size),
+                                    transform: pixel_transform(pixel_x, pixel_y, sprite_size),
                                     ..Default::default()
-                                },
-                                transform: transform(
+                                })
+                                .insert(Name::new(format!(
+                                    "pixel x:{:0>3} y:{:0>2}",
                                     pixel_x,
-                                    pixel_y,
-                                    pixel_size,
-                                    pixel_padding_size,
-                                ),
-                                ..Default::default()
-                            })
-                            .insert(Name::new(format!(
-                                "pixel x:{:0>2} y:{:0>2}",
-                                pixel_x,
-                                pixels_y - pixel_y - 1
-                            )))
-                            .insert(Pixel {
-                                x: pixel_x,
-                                y: pixels_y - pixel_y - 1,
-                            });
+                                    rows - pixel_y - 1
+                                )))
+                                .insert(Pixel {
+                                    emulator,
+                                    x: pixel_x,
+                                    y: rows - pixel_y - 1,
+                                });
+                        }
                     }
-                }
-            });
+                });
+        }
     }
 
+    /// Refits the displays whenever the window is resized, the camera keeps them centred
+    #[allow(clippy::needless_pass_by_value)]
+    pub(super) fn letterbox_pixels(
+        mut resized: EventReader<WindowResized>,
+        emulators: Res<Emulators>,
+        mut displays: Query<(&Display, &mut Transform), Without<Pixel>>,
+        mut pixels: Query<(&Pixel, &mut Transform, &mut Sprite), Without<Display>>,
+    ) {
+        let window_size = match resized
+            .iter()
+            .filter(|event| event.id == WindowId::primary())
+            .last()
+        {
+            Some(event) => Vec2::new(event.width, event.height),
+            None => return,
+        };
+
+        let letterbox = layout::letterbox(window_size, emulators.len());
+
+        for (display, mut transform) in displays.iter_mut() {
+            *transform = display_transform(&letterbox, emulators.len(), display.emulator);
+        }
+
+        let sprite_size = layout::sprite_size(letterbox.pixel_size);
+        let rows = layout::PIXELS_Y * layout::SUBPIXELS;
+
+        for (pixel, mut transform, mut sprite) in pixels.iter_mut() {
+            *transform = pixel_transform(pixel.x, rows - pixel.y - 1, sprite_size);
+            sprite.custom_size = Some(Vec2::splat(sprite_size));
+        }
+    }
+
+    /// Color of a pixel, `palette` being the palette's color for its location
+    pub(super) fn pixel_color(on: bool, palette: Option<[u8; 3]>, colors: &PixelColors) -> Color {
+        match (on, palette) {
+            (true, Some([red, green, blue])) => {
+                let mut color = Color::rgb_u8(red, green, blue);
+                color.set_a(colors.on.a());
+                color
+            }
+            (true, None) => colors.on,
+            (false, _) => colors.off,
+        }
+    }
+
+    /// Recolors the pixels of displays drawn to since the last frame, or every pixel when the colors changed
     #[allow(clippy::needless_pass_by_value)]
     pub(super) fn recolor_pixels(
-        emulator: Res<crate::chip8::emulator::Emulator>,
+        mut emulators: ResMut<Emulators>,
+        palette: Res<Palette>,
+        colors: Res<PixelColors>,
         mut query: Query<(&Pixel, &mut Sprite)>,
     ) {
+        let recolor_all = palette.is_changed() || colors.is_changed();
+        let dirty = emulators
+            .iter_mut()
+            .map(|emulator| emulator.take_display_dirty() || recolor_all)
+            .collect::<Vec<_>>();
+        if !dirty.contains(&true) {
+            return;
+        }
+
         for (pixel, mut sprite) in query.iter_mut() {
-            if emulator.is_pixel_on(pixel.x, pixel.y) {
-                sprite.color = Color::Rgba {
-                    red: 255.0,
-                    green: 255.0,
-                    blue: 255.0,
-                    alpha: 1.0,
-                };
-            } else {
-                sprite.color = Color::Rgba {
-                    red: 0.0,
-                    green: 0.0,
-                    blue: 0.0,
-                    alpha: 1.0,
-                };
+            if !dirty.get(pixel.emulator).copied().unwrap_or(false) {
+                continue;
             }
+
+            let on = emulators.get(pixel.emulator).map_or(false, |emulator| {
+                let (x, y) = layout::chip8_pixel(pixel.x, pixel.y, emulator.is_high_res());
+                emulator.is_pixel_on(x, y)
+            });
+            // palettes describe regions of the low resolution display
+            let (x, y) = layout::chip8_pixel(pixel.x, pixel.y, false);
+
+            sprite.color = pixel_color(on, palette.color(x, y), &colors);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::{layout, resource::PixelColors, system::pixel_color};
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn test_layout_scale() {
+        assert_eq!(layout::pixel_size(10), 10.0);
+        assert_eq!(layout::display_size(10), Vec2::new(640.0, 320.0));
+
+        assert_eq!(layout::pixel_size(20), 20.0);
+        assert_eq!(layout::display_size(20), Vec2::new(1280.0, 640.0));
+
+        assert_eq!(layout::window_size(10, 1), Vec2::new(640.0, 320.0));
+        assert_eq!(layout::window_size(10, 2), Vec2::new(1280.0, 320.0));
+    }
+
+    #[test]
+    fn test_layout_chip8_pixel() {
+        assert_eq!(layout::chip8_pixel(127, 63, true), (127, 63));
+        assert_eq!(layout::chip8_pixel(127, 63, false), (63, 31));
+        assert_eq!(layout::chip8_pixel(2, 3, false), (1, 1));
+        assert_eq!(layout::chip8_pixel(0, 0, false), (0, 0));
+    }
+
+    #[test]
+    fn test_layout_letterbox() {
+        assert_eq!(
+            layout::letterbox(Vec2::new(1000.0, 1000.0), 1),
+            layout::Letterbox {
+                pixel_size: 15.625,
+                display_size: Vec2::new(1000.0, 500.0),
+                margin: Vec2::new(0.0, 250.0),
+            }
+        );
+
+        assert_eq!(
+            layout::letterbox(Vec2::new(1000.0, 320.0), 1),
+            layout::Letterbox {
+                pixel_size: 10.0,
+                display_size: Vec2::new(640.0, 320.0),
+                margin: Vec2::new(180.0, 0.0),
+            }
+        );
+
+        assert_eq!(
+            layout::letterbox(Vec2::new(640.0, 320.0), 1),
+            layout::Letterbox {
+                pixel_size: 10.0,
+                display_size: Vec2::new(640.0, 320.0),
+                margin: Vec2::ZERO,
+            }
+        );
+
+        assert_eq!(
+            layout::letterbox(Vec2::new(1280.0, 1000.0), 2),
+            layout::Letterbox {
+                pixel_size: 10.0,
+                display_size: Vec2::new(640.0, 320.0),
+                margin: Vec2::new(0.0, 340.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_pixel_color() {
+        // Arrange
+        let colors = PixelColors::default();
+
+        // Act & Assert
+        assert_eq!(pixel_color(true, None, &colors), Color::WHITE);
+        assert_eq!(pixel_color(false, None, &colors), Color::BLACK);
+        assert_eq!(
+            pixel_color(true, Some([0xFF, 0x00, 0x00]), &colors),
+            Color::rgb_u8(0xFF, 0x00, 0x00)
+        );
+    }
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn test_pixel_color_overlay() {
+        // Arrange
+        let colors = PixelColors::overlay(Color::rgba(0.0, 1.0, 0.0, 0.5));
+
+        // Act & Assert
+        assert_eq!(pixel_color(false, None, &colors).a(), 0.0);
+        assert_eq!(
+            pixel_color(false, Some([0xFF, 0x00, 0x00]), &colors).a(),
+            0.0
+        );
+        assert_eq!(
+            pixel_color(true, None, &colors),
+            Color::rgba(0.0, 1.0, 0.0, 0.5)
+        );
+        assert_eq!(
+            pixel_color(true, Some([0xFF, 0x00, 0x00]), &colors).a(),
+            0.5
+        );
+    }
+}
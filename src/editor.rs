@@ -44,7 +44,14 @@ mod window {
 
     #[derive(Default)]
     pub struct EmulatorWindowState {
+        pub breakpoint: String,
         pub follow_program_counter: bool,
+        /// Formatted memory cells, only rows the emulator reports as written are reformatted
+        pub memory_cells: Vec<[String; 0x10]>,
+        /// Assembly typed into the snippet box
+        pub snippet: String,
+        /// Why the snippet last failed to assemble or load
+        pub snippet_error: Option<String>,
     }
 
     impl EditorWindow for EmulatorWindow {
@@ -57,23 +64,53 @@ mod window {
         fn ui(world: &mut World, mut cx: EditorWindowContext, ui: &mut egui::Ui) {
             let state = cx.state_mut::<EmulatorWindow>().unwrap();
 
-            let mut emulator = world
-                .get_resource_mut::<crate::chip8::emulator::Emulator>()
+            // only the first emulator is debugged when several run side by side
+            let mut emulators = world
+                .get_resource_mut::<crate::emulator::resource::Emulators>()
                 .unwrap();
+            let emulator = emulators.get_mut(0).unwrap();
 
             let debug = emulator.get_debug();
 
+            if state.memory_cells.is_empty() {
+                state.memory_cells = vec![Default::default(); 0x100];
+            }
+            for (address, bytes) in emulator.take_dirty_memory() {
+                let row = &mut state.memory_cells[usize::from(address) / 0x10];
+                for (cell, byte) in row.iter_mut().zip(bytes) {
+                    *cell = format!("{:02X}", byte);
+                }
+            }
+
+            let mut snippet = None;
+
             egui::ScrollArea::vertical()
                 .auto_shrink([false, false])
                 .show(ui, |ui| {
-                    register_ui(ui, &debug);
-                    memory_ui(ui, state, &debug);
-                    debug_ui(ui, &mut emulator);
+                    register_ui(ui, &debug, emulator);
+                    memory_ui(ui, state, &debug, emulator);
+                    disassembly_ui(ui, &debug, emulator);
+                    sprite_ui(ui, emulator);
+                    breakpoints_ui(ui, state, emulator);
+                    debug_ui(ui, emulator);
+                    snippet = snippet_ui(ui, state);
                 });
+
+            // every emulator runs the snippet, like a dropped ROM
+            if let Some(source) = snippet {
+                state.snippet_error = emulators
+                    .load_source(&source)
+                    .err()
+                    .map(|error| error.to_string());
+            }
         }
     }
 
-    fn register_ui(ui: &mut egui::Ui, debug: &crate::chip8::emulator::Debug) {
+    fn register_ui(
+        ui: &mut egui::Ui,
+        debug: &crate::chip8::emulator::Debug,
+        emulator: &mut crate::chip8::emulator::Emulator,
+    ) {
         egui::CollapsingHeader::new("Register").show(ui, |ui| {
             egui::Grid::new("register").striped(false).show(ui, |ui| {
                 ui.vertical(|ui| {
@@ -81,7 +118,10 @@ mod window {
                     ui.label("Index");
                     ui.horizontal(|ui| {
                         ui.label("I:");
-                        ui.label(format!("{:016X}", debug.register_i));
+                        let mut i = debug.register_i;
+                        if ui.add(egui::DragValue::new(&mut i)).changed() {
+                            emulator.set_i(i);
+                        }
                     });
                 });
 
@@ -124,9 +164,12 @@ mod window {
                         ui.label("Vx");
                         ui.end_row();
 
-                        for (x, vx) in debug.register_v.iter().enumerate() {
+                        for (x, vx) in (0..).zip(&debug.register_v) {
                             ui.label(format!("{:01X}", x));
-                            ui.label(format!("{:02X}", vx));
+                            let mut vx = *vx;
+                            if ui.add(egui::DragValue::new(&mut vx)).changed() {
+                                emulator.set_v(x, vx);
+                            }
                             ui.end_row();
                         }
                     });
@@ -157,6 +200,7 @@ mod window {
         ui: &mut egui::Ui,
         state: &mut EmulatorWindowState,
         debug: &crate::chip8::emulator::Debug,
+        emulator: &mut crate::chip8::emulator::Emulator,
     ) {
         egui::CollapsingHeader::new("Memory").show(ui, |ui| {
             let mut scroll_top = false;
@@ -186,19 +230,31 @@ mod window {
                         }
                         ui.end_row();
 
-                        for (i, bytes) in debug.memory_ram.chunks(16).enumerate() {
+                        for (i, cells) in state.memory_cells.iter_mut().enumerate() {
                             ui.label(format!("{:08X}", i * 0x10));
-                            for (j, byte) in bytes.iter().enumerate() {
-                                if i as u16 * 0x10 + j as u16 == debug.register_program_counter {
-                                    let response = ui.colored_label(
-                                        egui::Color32::YELLOW,
-                                        format!("{:02X}", byte),
-                                    );
-                                    if scroll_program_counter || state.follow_program_counter {
-                                        response.scroll_to_me(Some(egui::Align::Center))
+                            for (j, cell) in cells.iter_mut().enumerate() {
+                                let address = i as u16 * 0x10 + j as u16;
+                                let mut text_edit =
+                                    egui::TextEdit::singleline(cell).desired_width(16.0);
+                                if address == debug.register_program_counter {
+                                    text_edit = text_edit.text_color(egui::Color32::YELLOW);
+                                }
+
+                                let response = ui.add(text_edit);
+                                if address == debug.register_program_counter
+                                    && (scroll_program_counter || state.follow_program_counter)
+                                {
+                                    response.scroll_to_me(Some(egui::Align::Center));
+                                }
+
+                                // a poke marks the row written, which reformats the cell next frame
+                                if response.changed() && cell.len() == 2 {
+                                    if let Ok(byte) = u8::from_str_radix(cell, 16) {
+                                        emulator.set_ram_byte(address, byte);
                                     }
-                                } else {
-                                    ui.label(format!("{:02X}", byte));
+                                }
+                                if response.lost_focus() {
+                                    *cell = format!("{:02X}", emulator.memory_slice(address, 1)[0]);
                                 }
                             }
                             ui.end_row();
@@ -212,11 +268,129 @@ mod window {
         });
     }
 
+    fn disassembly_ui(
+        ui: &mut egui::Ui,
+        debug: &crate::chip8::emulator::Debug,
+        emulator: &crate::chip8::emulator::Emulator,
+    ) {
+        egui::CollapsingHeader::new("Disassembly").show(ui, |ui| {
+            let start = debug.register_program_counter.saturating_sub(0x8);
+
+            for (address, opcode, mnemonic) in emulator.disassemble_range(start, 16) {
+                let text = format!("{:03X}  {:04X}  {}", address, opcode, mnemonic);
+                if address == debug.register_program_counter {
+                    ui.colored_label(egui::Color32::YELLOW, text);
+                } else {
+                    ui.monospace(text);
+                }
+            }
+        });
+    }
+
+    fn sprite_ui(ui: &mut egui::Ui, emulator: &crate::chip8::emulator::Emulator) {
+        egui::CollapsingHeader::new("Sprite").show(ui, |ui| {
+            for byte in emulator.current_sprite(15) {
+                let pixels = format!("{:08b}", byte).replace('0', ".").replace('1', "#");
+                ui.monospace(format!("{:02X} {}", byte, pixels));
+            }
+        });
+    }
+
+    fn breakpoints_ui(
+        ui: &mut egui::Ui,
+        state: &mut EmulatorWindowState,
+        emulator: &mut crate::chip8::emulator::Emulator,
+    ) {
+        egui::CollapsingHeader::new("Breakpoints").show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.text_edit_singleline(&mut state.breakpoint);
+                if ui.button("Add").clicked() {
+                    if let Ok(program_counter) = u16::from_str_radix(state.breakpoint.trim(), 16) {
+                        emulator.add_breakpoint(program_counter);
+                        state.breakpoint.clear();
+                    }
+                }
+                if ui.button("Clear").clicked() {
+                    emulator.clear_breakpoints();
+                }
+                if emulator.is_paused() && ui.button("Resume").clicked() {
+                    emulator.set_paused(false);
+                }
+            });
+
+            let mut removed = None;
+            let hit = emulator.breakpoint_hit();
+            for program_counter in emulator.breakpoints() {
+                ui.horizontal(|ui| {
+                    let text = format!("{:03X}", program_counter);
+                    if hit == Some(*program_counter) {
+                        ui.colored_label(egui::Color32::YELLOW, text);
+                    } else {
+                        ui.label(text);
+                    }
+                    if ui.button("Remove").clicked() {
+                        removed = Some(*program_counter);
+                    }
+                });
+            }
+            if let Some(program_counter) = removed {
+                emulator.remove_breakpoint(program_counter);
+            }
+        });
+    }
+
+    /// Returns the snippet's source when Run is clicked
+    fn snippet_ui(ui: &mut egui::Ui, state: &mut EmulatorWindowState) -> Option<String> {
+        let mut run = None;
+
+        egui::CollapsingHeader::new("Snippet").show(ui, |ui| {
+            ui.add(
+                egui::TextEdit::multiline(&mut state.snippet)
+                    .code_editor()
+                    .desired_rows(4),
+            );
+
+            if ui.button("Run").clicked() {
+                run = Some(state.snippet.clone());
+            }
+
+            if let Some(error) = &state.snippet_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+        });
+
+        run
+    }
+
     fn debug_ui(ui: &mut egui::Ui, emulator: &mut crate::chip8::emulator::Emulator) {
+        use crate::chip8::diagnostics::Policy;
+
         egui::CollapsingHeader::new("Debug").show(ui, |ui| {
-            if ui.button("Step Execute").clicked() {
-                emulator.step_execute();
-            }
+            match emulator.last_executed() {
+                Some((address, mnemonic)) => {
+                    ui.label(format!("Last: {:03X} {}", address, mnemonic))
+                }
+                None => ui.label("Last: -"),
+            };
+            ui.label(format!("Next: {}", emulator.next_instruction()));
+
+            ui.horizontal(|ui| {
+                if ui.button("Step Back").clicked() {
+                    emulator.step_back();
+                }
+                if ui.button("Step Execute").clicked() {
+                    emulator.step_execute();
+                }
+            });
+
+            ui.horizontal(|ui| {
+                if ui.button("Reset Cold").clicked() {
+                    emulator.reset_cold();
+                }
+                if ui.button("Reset Warm").clicked() {
+                    emulator.reset_warm();
+                }
+            });
 
             if ui.button("Zero Delay Timer").clicked() {
                 emulator.zero_delay();
@@ -225,6 +399,51 @@ mod window {
             if ui.button("Zero Sound Timer").clicked() {
                 emulator.zero_sound();
             }
+
+            let mut policy = emulator.illegal_instruction_policy();
+            egui::ComboBox::from_label("Illegal instruction")
+                .selected_text(format!("{:?}", policy))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut policy, Policy::Ignore, "Ignore");
+                    ui.selectable_value(&mut policy, Policy::Warn, "Warn");
+                    ui.selectable_value(&mut policy, Policy::Trap, "Trap");
+                });
+            if policy != emulator.illegal_instruction_policy() {
+                emulator.set_illegal_instruction_policy(policy);
+            }
+
+            let mut clock_hz = emulator.clock_hz();
+            ui.add(egui::Slider::new(&mut clock_hz, 100..=5000).text("Clock (Hz)"));
+            if clock_hz != emulator.clock_hz() {
+                emulator.set_clock_hz(clock_hz);
+            }
+
+            ui.label(format!("Status: {:?}", emulator.status()));
+            match emulator.current_rom() {
+                Some(rom) => ui.label(format!("ROM: {} bytes", rom.len())),
+                None => ui.label("ROM: -"),
+            };
+
+            let diagnostics = emulator.diagnostics();
+            ui.label(format!(
+                "Illegal instructions: {}",
+                diagnostics.illegal_instructions()
+            ));
+            ui.label(format!(
+                "Reserved memory fetches: {}",
+                diagnostics.reserved_fetches()
+            ));
+            ui.label(format!(
+                "Self-modifying writes: {}",
+                diagnostics.self_modifying_writes()
+            ));
+            ui.label(format!(
+                "Self-recursive calls: {}",
+                diagnostics.self_recursive_calls()
+            ));
+            if let Some(fault) = emulator.fault() {
+                ui.colored_label(egui::Color32::RED, fault.to_string());
+            }
         });
     }
 }
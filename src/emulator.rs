@@ -5,41 +5,247 @@ pub(crate) mod plugin {
 
     impl bevy::prelude::Plugin for Plugin {
         fn build(&self, app: &mut bevy::prelude::App) {
-            app.add_startup_system(system::audio_setup)
-                .add_system(system::drag_and_drop_rom)
+            app.add_system(system::drag_and_drop_rom)
                 .add_system(system::emulate)
                 .add_system(system::keyboard);
         }
     }
 }
 
-mod resource {
+pub(crate) mod resource {
+    use bevy::input::keyboard::KeyCode;
+
+    use crate::chip8::{cartridge::Cartridge, emulator::Emulator, keypad::Key, quirks::Quirks};
+
+    /// Instructions the editor can step back through
+    const HISTORY_DEPTH: usize = if cfg!(feature = "editor") { 1000 } else { 0 };
+
+    /// Emulators displayed side by side, every one runs the same ROM from the same input
+    pub(crate) struct Emulators {
+        emulators: Vec<Emulator>,
+        /// Quirks each emulator is pinned to, `None` follows the loaded cartridge
+        quirks: Vec<Option<Quirks>>,
+    }
+
+    impl Default for Emulators {
+        fn default() -> Emulators {
+            Emulators::new(vec![None])
+        }
+    }
+
+    impl Emulators {
+        pub(crate) fn new(quirks: Vec<Option<Quirks>>) -> Emulators {
+            Emulators {
+                emulators: quirks.iter().map(|_| Emulator::builder().build()).collect(),
+                quirks,
+            }
+        }
+
+        pub(crate) fn len(&self) -> usize {
+            self.emulators.len()
+        }
+
+        pub(crate) fn get(&self, index: usize) -> Option<&Emulator> {
+            self.emulators.get(index)
+        }
+
+        #[cfg(feature = "editor")]
+        pub(crate) fn get_mut(&mut self, index: usize) -> Option<&mut Emulator> {
+            self.emulators.get_mut(index)
+        }
+
+        pub(crate) fn iter(&self) -> impl Iterator<Item = &Emulator> {
+            self.emulators.iter()
+        }
+
+        pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut Emulator> {
+            self.emulators.iter_mut()
+        }
+
+        pub(crate) fn emulate(&mut self, delta: &std::time::Duration) {
+            for emulator in &mut self.emulators {
+                emulator.emulate(delta);
+            }
+        }
+
+        /// Replaces every emulator with a fresh one running the cartridge
+        pub(crate) fn load_cartridge(&mut self, cartridge: &Cartridge) -> crate::Result<()> {
+            for (emulator, quirks) in self.emulators.iter_mut().zip(&self.quirks) {
+                let mut loaded = Emulator::builder()
+                    .history_depth(HISTORY_DEPTH)
+                    .quirks(quirks.unwrap_or_else(|| cartridge.quirks()))
+                    .build();
+                loaded.load_rom(cartridge.rom())?;
+                *emulator = loaded;
+            }
+
+            Ok(())
+        }
+
+        /// Assembles the source and loads it like a dropped ROM
+        #[cfg(feature = "editor")]
+        pub(crate) fn load_source(&mut self, source: &str) -> crate::Result<()> {
+            let rom = crate::chip8::asm::assemble(source)?;
+            self.load_cartridge(&Cartridge::from_bytes("snippet", &rom))
+        }
+    }
+
+    /// Keyboard keys pressing each CHIP-8 key, several keyboard keys can press the same CHIP-8 key
+    pub(crate) struct KeyBindings {
+        bindings: std::collections::BTreeMap<KeyCode, Key>,
+    }
+
+    impl Default for KeyBindings {
+        fn default() -> KeyBindings {
+            KeyBindings {
+                bindings: super::input::KEYS.into_iter().collect(),
+            }
+        }
+    }
+
+    impl KeyBindings {
+        /// Makes the keyboard key press the CHIP-8 key, replacing what it pressed before
+        #[allow(dead_code)]
+        pub(crate) fn bind(&mut self, keyboard: KeyCode, keypad: Key) {
+            self.bindings.insert(keyboard, keypad);
+        }
+
+        pub(crate) fn iter(&self) -> impl Iterator<Item = (KeyCode, Key)> + '_ {
+            self.bindings
+                .iter()
+                .map(|(&keyboard, &keypad)| (keyboard, keypad))
+        }
+    }
+}
+
+mod input {
     use bevy::prelude::*;
 
-    pub(crate) struct Beep(pub(crate) Handle<AudioSource>);
+    use super::resource::KeyBindings;
+    use crate::chip8::{
+        input::{InputSource, KeyEvent},
+        keypad::Key,
+    };
+
+    /// Default keyboard keys for the CHIP-8 keys `0x0` through `0xF`, in the COSMAC VIP keypad's layout
+    pub(super) const KEYS: [(KeyCode, Key); 0x10] = [
+        (KeyCode::X, Key::Key0),
+        (KeyCode::Key1, Key::Key1),
+        (KeyCode::Key2, Key::Key2),
+        (KeyCode::Key3, Key::Key3),
+        (KeyCode::Q, Key::Key4),
+        (KeyCode::W, Key::Key5),
+        (KeyCode::E, Key::Key6),
+        (KeyCode::A, Key::Key7),
+        (KeyCode::S, Key::Key8),
+        (KeyCode::D, Key::Key9),
+        (KeyCode::Z, Key::A),
+        (KeyCode::C, Key::B),
+        (KeyCode::Key4, Key::C),
+        (KeyCode::R, Key::D),
+        (KeyCode::F, Key::E),
+        (KeyCode::V, Key::F),
+    ];
+
+    /// Keyboard state of a single frame
+    #[derive(Clone)]
+    pub(super) struct Keyboard {
+        /// Bit `n` is set while CHIP-8 key `n` is held down
+        down: u16,
+        events: std::vec::IntoIter<KeyEvent>,
+    }
+
+    impl Keyboard {
+        pub(super) fn new(keys: &Input<KeyCode>, bindings: &KeyBindings) -> Keyboard {
+            let mut down = 0;
+            let mut events = Vec::new();
+
+            for (keyboard, keypad) in bindings.iter() {
+                if keys.pressed(keyboard) {
+                    down |= 1 << u8::from(keypad);
+                }
+                if keys.just_pressed(keyboard) {
+                    events.push(KeyEvent::Pressed(keypad));
+                }
+                if keys.just_released(keyboard) {
+                    events.push(KeyEvent::Released(keypad));
+                }
+            }
+
+            Keyboard {
+                down,
+                events: events.into_iter(),
+            }
+        }
+    }
+
+    impl InputSource for Keyboard {
+        fn is_key_down(&self, key: u8) -> bool {
+            self.down & (1 << key) != 0
+        }
+
+        fn poll_event(&mut self) -> Option<KeyEvent> {
+            self.events.next()
+        }
+    }
 }
 
 mod system {
-    use std::io::Read;
-
     use bevy::prelude::*;
 
-    use crate::chip8::emulator;
+    use super::resource::{Emulators, KeyBindings};
+    use crate::{
+        chip8::{cartridge::Cartridge, emulator::EmulatorStatus},
+        palette::Palette,
+    };
 
     pub(super) fn drag_and_drop_rom(
         mut commands: Commands,
         mut reader: EventReader<FileDragAndDrop>,
+        mut emulators: ResMut<Emulators>,
     ) {
         for event in reader.iter() {
             match event {
                 FileDragAndDrop::DroppedFile { id: _, path_buf } => {
-                    let mut rom = Vec::new();
-                    let mut file = std::fs::File::open(path_buf).expect("failed to open file");
-                    file.read_to_end(&mut rom).expect("failed to read file");
+                    let rom = match std::fs::read(path_buf) {
+                        Ok(rom) => rom,
+                        Err(error) => {
+                            error!("failed to read {}: {}", path_buf.display(), error);
+                            continue;
+                        }
+                    };
+
+                    let name = path_buf
+                        .file_stem()
+                        .map(|name| name.to_string_lossy())
+                        .unwrap_or_default();
+                    let cartridge = Cartridge::from_bytes(&name, &rom);
+                    info!(
+                        "loaded {} ({:?}, crc32 {:08X})",
+                        cartridge.name(),
+                        cartridge.platform(),
+                        cartridge.checksum()
+                    );
+
+                    if let Err(error) = emulators.load_cartridge(&cartridge) {
+                        error!("failed to load {}: {}", cartridge.name(), error);
+                        continue;
+                    }
+
+                    for (index, emulator) in emulators.iter().enumerate() {
+                        for warning in emulator.rom_warnings() {
+                            warn!("emulator {}: {}", index, warning);
+                        }
+                    }
 
-                    let mut emulator = emulator::Emulator::new();
-                    emulator.load_rom(&rom).expect("failed to load rom");
-                    commands.insert_resource(emulator);
+                    let palette = match std::fs::read_to_string(path_buf.with_extension("pal")) {
+                        Ok(sidecar) => Palette::parse(&sidecar).unwrap_or_else(|error| {
+                            warn!("ignoring palette for {}: {}", cartridge.name(), error);
+                            Palette::default()
+                        }),
+                        Err(_) => Palette::default(),
+                    };
+                    commands.insert_resource(palette);
                 }
                 FileDragAndDrop::HoveredFile { id: _, path_buf: _ }
                 | FileDragAndDrop::HoveredFileCancelled { id: _ } => (),
@@ -50,55 +256,211 @@ mod system {
     #[allow(clippy::needless_pass_by_value)]
     pub(super) fn emulate(
         time: Res<Time>,
-        asset_server: Res<AssetServer>,
-        audio: Res<Audio>,
-        beep: Res<super::resource::Beep>,
-        mut emulator: ResMut<crate::chip8::emulator::Emulator>,
+        mut emulators: ResMut<Emulators>,
+        mut illegal_instructions: Local<Vec<u32>>,
+        mut reserved_fetches: Local<Vec<u32>>,
+        mut self_modifying_writes: Local<Vec<u32>>,
+        mut self_recursive_calls: Local<Vec<u32>>,
+        mut statuses: Local<Vec<Option<EmulatorStatus>>>,
     ) {
-        emulator.emulate(&time.delta());
+        emulators.emulate(&time.delta());
 
-        if emulator.is_beeping() {
-            audio.play(asset_server.get_handle(&beep.0));
+        illegal_instructions.resize(emulators.len(), 0);
+        reserved_fetches.resize(emulators.len(), 0);
+        self_modifying_writes.resize(emulators.len(), 0);
+        self_recursive_calls.resize(emulators.len(), 0);
+        statuses.resize(emulators.len(), None);
+
+        for (index, emulator) in emulators.iter().enumerate() {
+            if Some(emulator.status()) != statuses[index] {
+                match emulator.status() {
+                    EmulatorStatus::Running => (),
+                    EmulatorStatus::Paused => {
+                        if let Some(address) = emulator.breakpoint_hit() {
+                            info!("emulator {}: breakpoint at {:03X}", index, address);
+                        }
+                        if let Some(hit) = emulator.watch_hit() {
+                            info!(
+                                "emulator {}: instruction at {:03X} accessed watched {:03X} ({:?})",
+                                index, hit.program_counter, hit.address, hit.access
+                            );
+                        }
+                    }
+                    EmulatorStatus::Halted => info!("emulator {}: program halted", index),
+                    EmulatorStatus::Exited => info!("emulator {}: program exited", index),
+                    EmulatorStatus::Error(fault) => error!("emulator {}: {}", index, fault),
+                }
+                statuses[index] = Some(emulator.status());
+            }
+
+            let diagnostics = emulator.diagnostics();
+            if illegal_instructions[index] == 0 && diagnostics.illegal_instructions() > 0 {
+                warn!(
+                    "emulator {}: program executed an instruction the display mode or quirks do not support",
+                    index
+                );
+            }
+            illegal_instructions[index] = diagnostics.illegal_instructions();
+
+            if reserved_fetches[index] == 0 && diagnostics.reserved_fetches() > 0 {
+                warn!(
+                    "emulator {}: program counter entered reserved memory below 0x200",
+                    index
+                );
+            }
+            reserved_fetches[index] = diagnostics.reserved_fetches();
+
+            if self_modifying_writes[index] == 0 && diagnostics.self_modifying_writes() > 0 {
+                warn!("emulator {}: program modified its own instructions", index);
+            }
+            self_modifying_writes[index] = diagnostics.self_modifying_writes();
+
+            if self_recursive_calls[index] == 0 && diagnostics.self_recursive_calls() > 0 {
+                warn!("emulator {}: subroutine called itself", index);
+            }
+            self_recursive_calls[index] = diagnostics.self_recursive_calls();
         }
     }
 
     #[allow(clippy::needless_pass_by_value)]
     pub(super) fn keyboard(
         keys: Res<Input<KeyCode>>,
-        mut emulator: ResMut<crate::chip8::emulator::Emulator>,
+        bindings: Res<KeyBindings>,
+        mut emulators: ResMut<Emulators>,
     ) {
-        use crate::chip8::keypad::Key;
-
-        for (keyboard, keypad) in [
-            (KeyCode::X, Key::Key0),
-            (KeyCode::Key1, Key::Key1),
-            (KeyCode::Key2, Key::Key2),
-            (KeyCode::Key3, Key::Key3),
-            (KeyCode::Q, Key::Key4),
-            (KeyCode::W, Key::Key5),
-            (KeyCode::E, Key::Key6),
-            (KeyCode::A, Key::Key7),
-            (KeyCode::S, Key::Key8),
-            (KeyCode::D, Key::Key9),
-            (KeyCode::Z, Key::A),
-            (KeyCode::C, Key::B),
-            (KeyCode::Key4, Key::C),
-            (KeyCode::R, Key::D),
-            (KeyCode::F, Key::E),
-            (KeyCode::V, Key::F),
-        ] {
-            if keys.just_pressed(keyboard) {
-                emulator.key_pressed(keypad);
+        if keys.just_pressed(KeyCode::F5) {
+            for emulator in emulators.iter_mut() {
+                emulator.reset_cold();
             }
-            if keys.just_released(keyboard) {
-                emulator.key_released(keypad);
+        }
+
+        if keys.just_pressed(KeyCode::Space) {
+            for emulator in emulators.iter_mut() {
+                emulator.toggle_pause();
             }
         }
+
+        let keyboard = super::input::Keyboard::new(&keys, &bindings);
+
+        for emulator in emulators.iter_mut() {
+            emulator.poll_input(&mut keyboard.clone());
+        }
     }
+}
 
-    #[allow(clippy::needless_pass_by_value)]
-    pub(super) fn audio_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
-        let music = asset_server.load("beep.ogg");
-        commands.insert_resource(super::resource::Beep(music));
+#[cfg(test)]
+mod tests {
+    use bevy::input::{keyboard::KeyCode, Input};
+
+    use super::{
+        input::Keyboard,
+        resource::{Emulators, KeyBindings},
+    };
+    use crate::chip8::{
+        cartridge::Cartridge,
+        input::{InputSource, KeyEvent},
+        keypad::Key,
+        quirks::Quirks,
+    };
+
+    #[cfg(feature = "editor")]
+    #[test]
+    fn test_emulators_load_source() {
+        // Arrange
+        let mut emulators = Emulators::new(vec![None, Some(Quirks::cosmac())]);
+
+        // Act
+        emulators
+            .load_source("LD I, 0x050\nDRW V0, V0, 0x5\nloop: JP loop")
+            .unwrap();
+        emulators.emulate(&std::time::Duration::from_secs(1));
+
+        // Assert
+        assert!(emulators.iter().all(|emulator| emulator.is_pixel_on(0, 0)));
+        assert!(emulators.load_source("LD V0").is_err());
+    }
+
+    #[test]
+    fn test_key_bindings_bind() {
+        // Arrange
+        let mut bindings = KeyBindings::default();
+        bindings.bind(KeyCode::Q, Key::Key7);
+        let mut keys = Input::<KeyCode>::default();
+        keys.press(KeyCode::Q);
+
+        // Act
+        let mut keyboard = Keyboard::new(&keys, &bindings);
+
+        // Assert
+        assert!(keyboard.is_key_down(0x7));
+        assert!(!keyboard.is_key_down(0x4), "Q no longer presses 4");
+        assert_eq!(keyboard.poll_event(), Some(KeyEvent::Pressed(Key::Key7)));
+        assert_eq!(keyboard.poll_event(), None);
+    }
+
+    #[test]
+    fn test_key_bindings_default() {
+        // Arrange
+        let bindings = KeyBindings::default();
+        let mut keys = Input::<KeyCode>::default();
+        keys.press(KeyCode::V);
+
+        // Act
+        let keyboard = Keyboard::new(&keys, &bindings);
+
+        // Assert
+        assert!(keyboard.is_key_down(0xF));
+    }
+
+    #[test]
+    fn test_emulators_quirks() {
+        // Arrange
+        let mut emulators = Emulators::new(vec![
+            Some(Quirks {
+                shift_in_place: true,
+                ..Quirks::default()
+            }),
+            Some(Quirks {
+                shift_in_place: false,
+                ..Quirks::default()
+            }),
+        ]);
+        let rom = [
+            0x60, 0x05, // LD V0, 0x05
+            0xE0, 0x9E, // SKP V0
+            0x12, 0x02, // JP 0x202
+            0x61, 0x02, // LD V1, 0x02
+            0x62, 0x10, // LD V2, 0x10
+            0x82, 0x16, // SHR V2, V1
+            0xF2, 0x29, // LD F, V2
+            0x63, 0x00, // LD V3, 0x00
+            0xD3, 0x35, // DRW V3, V3, 0x5
+            0x12, 0x12, // JP 0x212
+        ];
+        emulators
+            .load_cartridge(&Cartridge::from_bytes("shift", &rom))
+            .unwrap();
+
+        // Act
+        emulators.emulate(&std::time::Duration::from_secs(1));
+
+        // Assert
+        assert!(emulators.iter().all(|emulator| !emulator.is_pixel_on(0, 0)));
+
+        // Act
+        for emulator in emulators.iter_mut() {
+            emulator.key_pressed(Key::Key5);
+        }
+        emulators.emulate(&std::time::Duration::from_secs(1));
+
+        // Assert
+        let first = emulators.get(0).unwrap();
+        assert!(first.is_pixel_on(0, 0), "drew 8 from VX >> 1");
+        assert!(first.is_pixel_on(3, 0));
+
+        let second = emulators.get(1).unwrap();
+        assert!(!second.is_pixel_on(0, 0), "drew 1 from VY >> 1");
+        assert!(second.is_pixel_on(2, 0));
+        assert!(!second.is_pixel_on(3, 0));
     }
 }
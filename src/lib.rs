@@ -1,18 +1,29 @@
 #![warn(clippy::pedantic)]
 
+#[cfg(feature = "app")]
 pub mod app;
 
+#[cfg(feature = "app")]
 mod camera;
 
-mod chip8;
+pub mod chip8;
 
+#[cfg(feature = "app")]
 mod display;
 
+#[cfg(feature = "app")]
 mod emulator;
 
+#[cfg(feature = "app")]
+mod palette;
+
 #[cfg(feature = "editor")]
 mod editor;
 
+#[cfg(feature = "app")]
+mod sound;
+
+#[cfg(feature = "app")]
 mod window;
 
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
@@ -0,0 +1,108 @@
+/// Colors for on pixels by screen region, loaded from a `.pal` sidecar next to the ROM.
+///
+/// Each line is `x y width height RRGGBB`, blank lines and lines starting with `#` are ignored.
+/// Where regions overlap the first listed wins, pixels outside every region stay monochrome.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct Palette {
+    regions: Vec<Region>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Region {
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+    color: [u8; 3],
+}
+
+impl Palette {
+    pub(crate) fn parse(sidecar: &str) -> crate::Result<Palette> {
+        let mut regions = Vec::new();
+
+        for (number, line) in sidecar.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            if fields.len() != 5 {
+                return Err(format!("palette line {}: expected 5 fields", number + 1).into());
+            }
+
+            let color = u32::from_str_radix(fields[4], 16)?;
+            if fields[4].len() != 6 {
+                return Err(
+                    format!("palette line {}: expected an RRGGBB color", number + 1).into(),
+                );
+            }
+            let [_, red, green, blue] = color.to_be_bytes();
+
+            regions.push(Region {
+                x: fields[0].parse()?,
+                y: fields[1].parse()?,
+                width: fields[2].parse()?,
+                height: fields[3].parse()?,
+                color: [red, green, blue],
+            });
+        }
+
+        Ok(Palette { regions })
+    }
+
+    /// Color of an on pixel at the location, `None` when it should stay monochrome
+    pub(crate) fn color(&self, x: u8, y: u8) -> Option<[u8; 3]> {
+        self.regions
+            .iter()
+            .find(|region| region.contains(x, y))
+            .map(|region| region.color)
+    }
+}
+
+impl Region {
+    fn contains(&self, x: u8, y: u8) -> bool {
+        (self.x..self.x.saturating_add(self.width)).contains(&x)
+            && (self.y..self.y.saturating_add(self.height)).contains(&y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color() {
+        // Arrange
+        let palette = Palette::parse(
+            "# score bar\n\
+             0 0 64 4 FF0000\n\
+             \n\
+             0 0 8 32 00FF00\n\
+             56 0 8 32 0000FF\n",
+        )
+        .unwrap();
+
+        // Act & Assert
+        assert_eq!(palette.color(0, 0), Some([0xFF, 0x00, 0x00]));
+        assert_eq!(palette.color(63, 3), Some([0xFF, 0x00, 0x00]));
+        assert_eq!(palette.color(0, 4), Some([0x00, 0xFF, 0x00]));
+        assert_eq!(palette.color(7, 31), Some([0x00, 0xFF, 0x00]));
+        assert_eq!(palette.color(56, 4), Some([0x00, 0x00, 0xFF]));
+        assert_eq!(palette.color(8, 4), None);
+        assert_eq!(palette.color(55, 31), None);
+    }
+
+    #[test]
+    fn test_color_default() {
+        assert_eq!(Palette::default().color(0, 0), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Palette::parse("0 0 64 4").is_err());
+        assert!(Palette::parse("0 0 64 4 FF00").is_err());
+        assert!(Palette::parse("0 0 64 4 GG0000").is_err());
+        assert!(Palette::parse("0 0 256 4 FF0000").is_err());
+    }
+}
@@ -0,0 +1,147 @@
+pub(crate) mod resource {
+    use bevy::{audio::AudioSink, prelude::Handle};
+
+    /// Loudness of the beep, from silent at 0 to full scale at 1
+    pub(crate) struct Sound {
+        pub(crate) volume: f32,
+    }
+
+    impl Default for Sound {
+        fn default() -> Sound {
+            Sound { volume: 0.25 }
+        }
+    }
+
+    /// Looping tone that is paused while no emulator is beeping
+    pub(crate) struct Tone(pub(crate) Handle<AudioSink>);
+}
+
+pub(crate) mod wave {
+    /// Pitch of the beep, the COSMAC VIP's tone was close enough to A4
+    pub(crate) const FREQUENCY: u32 = 440;
+
+    pub(crate) const SAMPLE_RATE: u32 = 44_100;
+
+    /// 16-bit mono WAV of a square wave lasting a second, a whole number of periods so it loops without a click
+    pub(crate) fn square_wav(frequency: u32, sample_rate: u32) -> Vec<u8> {
+        const AMPLITUDE: i16 = i16::MAX / 2;
+
+        let data_len = sample_rate * 2;
+
+        let mut wav = Vec::with_capacity(44 + data_len as usize);
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+        wav.extend_from_slice(b"WAVE");
+
+        wav.extend_from_slice(b"fmt ");
+        wav.extend_from_slice(&16_u32.to_le_bytes());
+        wav.extend_from_slice(&1_u16.to_le_bytes()); // PCM
+        wav.extend_from_slice(&1_u16.to_le_bytes()); // mono
+        wav.extend_from_slice(&sample_rate.to_le_bytes());
+        wav.extend_from_slice(&(sample_rate * 2).to_le_bytes()); // bytes a second
+        wav.extend_from_slice(&2_u16.to_le_bytes()); // bytes a sample
+        wav.extend_from_slice(&16_u16.to_le_bytes()); // bits a sample
+
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        for sample in 0..u64::from(sample_rate) {
+            // high for the first half of every period, low for the second
+            let half_periods = sample * u64::from(frequency) * 2 / u64::from(sample_rate);
+            let amplitude = if half_periods % 2 == 0 {
+                AMPLITUDE
+            } else {
+                -AMPLITUDE
+            };
+            wav.extend_from_slice(&amplitude.to_le_bytes());
+        }
+
+        wav
+    }
+}
+
+pub(crate) mod plugin {
+    use super::system;
+
+    pub(crate) struct Plugin;
+
+    impl bevy::prelude::Plugin for Plugin {
+        fn build(&self, app: &mut bevy::prelude::App) {
+            app.add_startup_system(system::spawn_tone)
+                .add_system(system::beep);
+        }
+    }
+}
+
+mod system {
+    use bevy::{audio::AudioSink, prelude::*};
+
+    use super::{
+        resource::{Sound, Tone},
+        wave,
+    };
+    use crate::emulator::resource::Emulators;
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub(super) fn spawn_tone(
+        mut commands: Commands,
+        audio: Res<Audio>,
+        mut sources: ResMut<Assets<AudioSource>>,
+        sinks: Res<Assets<AudioSink>>,
+    ) {
+        let source = sources.add(AudioSource {
+            bytes: wave::square_wav(wave::FREQUENCY, wave::SAMPLE_RATE).into(),
+        });
+
+        // starts silent, the sink only exists once the audio output has picked the tone up
+        let sink = audio.play_with_settings(source, PlaybackSettings::LOOP.with_volume(0.0));
+        commands.insert_resource(Tone(sinks.get_handle(sink)));
+    }
+
+    /// Plays the tone for as long as any emulator beeps, rather than restarting it every frame
+    #[allow(clippy::needless_pass_by_value)]
+    pub(super) fn beep(
+        emulators: Res<Emulators>,
+        sound: Res<Sound>,
+        tone: Res<Tone>,
+        sinks: Res<Assets<AudioSink>>,
+    ) {
+        let sink = match sinks.get(&tone.0) {
+            Some(sink) => sink,
+            None => return,
+        };
+
+        // the emulators beep in unison, one sound is enough
+        if emulators
+            .iter()
+            .any(crate::chip8::emulator::Emulator::is_beeping)
+        {
+            sink.set_volume(sound.volume);
+            sink.play();
+        } else {
+            sink.pause();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wave;
+
+    #[test]
+    fn test_square_wav() {
+        // Act
+        let wav = wave::square_wav(440, 44_100);
+
+        // Assert
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..16], b"WAVEfmt ");
+        assert_eq!(&wav[36..40], b"data");
+        assert_eq!(wav.len(), 44 + 44_100 * 2);
+
+        let sample = |n: usize| i16::from_le_bytes([wav[44 + n * 2], wav[45 + n * 2]]);
+        assert!(sample(0) > 0);
+        assert!(sample(49) > 0, "end of the first half period");
+        assert!(sample(51) < 0, "start of the second half period");
+        assert!(sample(44_099) < 0, "end of the last period");
+    }
+}
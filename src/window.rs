@@ -1,8 +1,33 @@
 use bevy::prelude::*;
 
-pub(crate) fn resource() -> WindowDescriptor {
+use crate::display::{layout, resource::Scale};
+
+pub(crate) fn resource(scale: &Scale, displays: usize) -> WindowDescriptor {
+    let window_size = layout::window_size(scale.0, displays);
+
     WindowDescriptor {
         title: "CHIP-8 Emulator".into(),
+        width: window_size.x,
+        height: window_size.y,
         ..Default::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::display::resource::Scale;
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn test_resource_scale() {
+        let window = super::resource(&Scale(10), 1);
+
+        assert_eq!(window.width, 640.0);
+        assert_eq!(window.height, 320.0);
+
+        let window = super::resource(&Scale(10), 2);
+
+        assert_eq!(window.width, 1280.0);
+        assert_eq!(window.height, 320.0);
+    }
+}
@@ -0,0 +1,90 @@
+//! Runs ROMs headlessly and compares the display against the reference PNGs in `tests/reference`.
+//!
+//! After an intentional rendering change, regenerate the references with:
+//!
+//! ```terminal
+//! UPDATE_REFERENCES=1 cargo test --test reference
+//! ```
+
+use chip_8_emulator::chip8::{cartridge::Cartridge, emulator::Emulator};
+
+/// Emulates the ROM for a number of 60 Hz frames and returns the RGBA framebuffer
+fn render(rom: &str, frames: u32) -> (u32, u32, Vec<u8>) {
+    let bytes = std::fs::read(format!("./roms/{}", rom)).unwrap();
+    let cartridge = Cartridge::from_bytes(rom, &bytes);
+
+    let mut emulator = Emulator::new();
+    emulator.load_cartridge(&cartridge).unwrap();
+    emulator.tick_frames(frames);
+
+    let (width, height) = emulator.framebuffer_size();
+    (
+        u32::from(width),
+        u32::from(height),
+        emulator.framebuffer_rgba(),
+    )
+}
+
+fn write_reference(path: &str, width: u32, height: u32, rgba: &[u8]) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::RGBA);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .unwrap()
+        .write_image_data(rgba)
+        .unwrap();
+}
+
+fn read_reference(path: &str) -> (u32, u32, Vec<u8>) {
+    let file = std::fs::File::open(path)
+        .unwrap_or_else(|_| panic!("missing {}, run with UPDATE_REFERENCES=1", path));
+    let (info, mut reader) = png::Decoder::new(file).read_info().unwrap();
+    assert_eq!(info.color_type, png::ColorType::RGBA, "{}", path);
+    assert_eq!(info.bit_depth, png::BitDepth::Eight, "{}", path);
+
+    let mut rgba = vec![0; info.buffer_size()];
+    reader.next_frame(&mut rgba).unwrap();
+    (info.width, info.height, rgba)
+}
+
+/// Compares a ROM's display after a number of frames with `tests/reference/<name>.png`
+fn assert_reference(rom: &str, frames: u32, name: &str) {
+    let path = format!("./tests/reference/{}.png", name);
+    let (width, height, rgba) = render(rom, frames);
+
+    if std::env::var_os("UPDATE_REFERENCES").is_some() {
+        write_reference(&path, width, height, &rgba);
+    }
+
+    let (reference_width, reference_height, reference_rgba) = read_reference(&path);
+    assert_eq!(
+        (width, height),
+        (reference_width, reference_height),
+        "{}",
+        path
+    );
+
+    let mismatches = rgba
+        .chunks(4)
+        .zip(reference_rgba.chunks(4))
+        .filter(|(actual, expected)| actual != expected)
+        .count();
+    assert_eq!(mismatches, 0, "{} pixels differ from {}", mismatches, path);
+}
+
+#[test]
+fn test_ibm_logo() {
+    assert_reference("IBM Logo.ch8", 60, "ibm_logo");
+}
+
+#[test]
+fn test_opcode() {
+    assert_reference("test_opcode.ch8", 60, "test_opcode");
+}
+
+#[test]
+fn test_chip8_test_rom() {
+    assert_reference("chip8-test-rom.ch8", 60, "chip8_test_rom");
+}